@@ -0,0 +1,342 @@
+use crate::vector::{Vec2, Vec3};
+
+/// A 2d axis aligned bounding box
+///
+/// The box is closed, points lying on its edges are considered to be inside of it
+#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+pub struct Aabb2 {
+    /// The corner with the smallest coordinates
+    pub min: Vec2,
+    /// The corner with the largest coordinates
+    pub max: Vec2,
+}
+
+impl Aabb2 {
+    /// Creates a new box from its minimal and maximal corner
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::ONE);
+    ///
+    /// assert_eq!(aabb.max, Vec2::ONE);
+    /// ```
+    #[must_use]
+    pub const fn new(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates the smallest box containing all of `points`
+    ///
+    /// Returns `None` if `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let points = [
+    ///     Vec2::new(1.into(), 5.into()),
+    ///     Vec2::new((-2).into(), 3.into()),
+    ///     Vec2::new(4.into(), 0.into()),
+    /// ];
+    ///
+    /// let aabb = Aabb2::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(aabb, Aabb2::new(Vec2::new((-2).into(), 0.into()), Vec2::new(4.into(), 5.into())));
+    /// ```
+    #[must_use]
+    pub fn from_points(points: &[Vec2]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+
+        Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| {
+            Self::new(aabb.min.min(*point), aabb.max.max(*point))
+        }))
+    }
+
+    /// Returns the point in the middle of the box
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::new(4.into(), 2.into()));
+    ///
+    /// assert_eq!(aabb.center(), Vec2::new(2.into(), 1.into()));
+    /// ```
+    #[must_use]
+    pub fn center(&self) -> Vec2 {
+        self.min.midpoint(self.max)
+    }
+
+    /// Returns the width and height of the box
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let aabb = Aabb2::new(Vec2::ONE, Vec2::new(4.into(), 2.into()));
+    ///
+    /// assert_eq!(aabb.size(), Vec2::new(3.into(), 1.into()));
+    /// ```
+    #[must_use]
+    pub fn size(&self) -> Vec2 {
+        self.max - self.min
+    }
+
+    /// Checks whether `point` lies inside of the box or on its boundary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let aabb = Aabb2::new(Vec2::ZERO, Vec2::new(2.into(), 2.into()));
+    ///
+    /// assert!(aabb.contains(Vec2::ONE));
+    /// assert!(aabb.contains(Vec2::new(2.into(), 0.into())));
+    /// assert!(!aabb.contains(Vec2::new(3.into(), 0.into())));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+
+    /// Checks whether two boxes overlap, boxes touching only along an edge count as overlapping
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let a = Aabb2::new(Vec2::ZERO, Vec2::new(2.into(), 2.into()));
+    /// let b = Aabb2::new(Vec2::ONE, Vec2::new(3.into(), 3.into()));
+    ///
+    /// assert!(a.intersects(b));
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+}
+
+/// A 3d axis aligned bounding box
+///
+/// The box is closed, points lying on its faces are considered to be inside of it
+#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+pub struct Aabb3 {
+    /// The corner with the smallest coordinates
+    pub min: Vec3,
+    /// The corner with the largest coordinates
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    /// Creates a new box from its minimal and maximal corner
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::ONE);
+    ///
+    /// assert_eq!(aabb.max, Vec3::ONE);
+    /// ```
+    #[must_use]
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates the smallest box containing all of `points`
+    ///
+    /// Returns `None` if `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let points = [Vec3::new(1, 5, -1), Vec3::new(-2, 3, 0), Vec3::new(4, 0, 2)];
+    ///
+    /// let aabb = Aabb3::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(aabb, Aabb3::new(Vec3::new(-2, 0, -1), Vec3::new(4, 5, 2)));
+    /// ```
+    #[must_use]
+    pub fn from_points(points: &[Vec3]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+
+        Some(rest.iter().fold(Self::new(*first, *first), |aabb, point| {
+            Self::new(aabb.min.min(*point), aabb.max.max(*point))
+        }))
+    }
+
+    /// Checks whether `point` lies inside of the box or on its boundary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::new(2, 2, 2));
+    ///
+    /// assert!(aabb.contains(Vec3::ONE));
+    /// assert!(aabb.contains(Vec3::new(2, 0, 1)));
+    /// assert!(!aabb.contains(Vec3::new(1, 1, 3)));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, point: Vec3) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+            && self.min.z <= point.z
+            && point.z <= self.max.z
+    }
+
+    /// Checks whether two boxes overlap, boxes touching only along a face count as overlapping
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let a = Aabb3::new(Vec3::ZERO, Vec3::new(2, 2, 2));
+    /// let b = Aabb3::new(Vec3::ONE, Vec3::new(3, 3, 3));
+    ///
+    /// assert!(a.intersects(b));
+    /// ```
+    #[must_use]
+    pub fn intersects(&self, other: Self) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+            && self.min.z <= other.max.z
+            && other.min.z <= self.max.z
+    }
+
+    /// Returns the point in the middle of the box
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let aabb = Aabb3::new(Vec3::ZERO, Vec3::new(4, 2, 6));
+    ///
+    /// assert_eq!(aabb.center(), Vec3::new(2, 1, 3));
+    /// ```
+    #[must_use]
+    pub fn center(&self) -> Vec3 {
+        self.min.midpoint(self.max)
+    }
+
+    /// Returns the width, height and depth of the box
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let aabb = Aabb3::new(Vec3::ONE, Vec3::new(4, 2, 6));
+    ///
+    /// assert_eq!(aabb.size(), Vec3::new(3, 1, 5));
+    /// ```
+    #[must_use]
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aabb::{Aabb2, Aabb3};
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn containment() {
+        let aabb = Aabb2::new(Vec2::ZERO, Vec2::new(4.into(), 2.into()));
+
+        assert!(aabb.contains(Vec2::new(3.into(), 1.into())));
+        assert!(aabb.contains(Vec2::new(4.into(), 2.into())));
+        assert!(!aabb.contains(Vec2::new(5.into(), 1.into())));
+        assert!(!aabb.contains(Vec2::new(1.into(), (-1).into())));
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Aabb2::new(Vec2::ZERO, Vec2::new(2.into(), 2.into()));
+        let b = Aabb2::new(Vec2::ONE, Vec2::new(3.into(), 3.into()));
+        let c = Aabb2::new(Vec2::new(5.into(), 0.into()), Vec2::new(6.into(), 1.into()));
+
+        assert!(a.intersects(b));
+        assert!(b.intersects(a));
+        assert!(!a.intersects(c));
+    }
+
+    #[test]
+    fn from_points_2d() {
+        let points = [
+            Vec2::new(3.into(), (-1).into()),
+            Vec2::new((-2).into(), 4.into()),
+            Vec2::new(0.into(), 0.into()),
+        ];
+
+        let aabb = Aabb2::from_points(&points).unwrap();
+
+        assert_eq!(aabb.min, Vec2::new((-2).into(), (-1).into()));
+        assert_eq!(aabb.max, Vec2::new(3.into(), 4.into()));
+        assert!(points.iter().all(|point| aabb.contains(*point)));
+        assert_eq!(aabb.size(), Vec2::new(5.into(), 5.into()));
+        assert_eq!(aabb.center(), aabb.min + aabb.size() / 2.into());
+        assert_eq!(Aabb2::from_points(&[]), None);
+    }
+
+    #[test]
+    fn containment_3d() {
+        let aabb = Aabb3::new(Vec3::new(-1, 0, 0), Vec3::new(1, 2, 3));
+
+        assert!(aabb.contains(Vec3::new(0, 1, 1)));
+        assert!(aabb.contains(Vec3::new(1, 2, 3)));
+        assert!(aabb.contains(Vec3::new(-1, 0, 2)));
+        assert!(!aabb.contains(Vec3::new(0, 1, 4)));
+        assert!(!aabb.contains(Vec3::new(-2, 1, 1)));
+    }
+
+    #[test]
+    fn intersection_3d() {
+        let a = Aabb3::new(Vec3::ZERO, Vec3::new(2, 2, 2));
+        let touching = Aabb3::new(Vec3::new(2, 0, 0), Vec3::new(3, 1, 1));
+        let disjoint = Aabb3::new(Vec3::new(0, 0, 3), Vec3::new(1, 1, 4));
+
+        assert!(a.intersects(touching));
+        assert!(touching.intersects(a));
+        assert!(!a.intersects(disjoint));
+        assert!(!disjoint.intersects(a));
+    }
+
+    #[test]
+    fn from_points_3d() {
+        let points = [
+            Vec3::new(3, -1, 2),
+            Vec3::new(-2, 4, 0),
+            Vec3::new(1, 1, -6),
+        ];
+
+        let aabb = Aabb3::from_points(&points).unwrap();
+
+        assert_eq!(aabb, Aabb3::new(Vec3::new(-2, -1, -6), Vec3::new(3, 4, 2)));
+        assert!(points.iter().all(|point| aabb.contains(*point)));
+        assert_eq!(aabb.size(), Vec3::new(5, 5, 8));
+        assert_eq!(
+            aabb.center(),
+            Vec3::new(
+                SignedFractional::from_num(0.5),
+                SignedFractional::from_num(1.5),
+                -2
+            )
+        );
+        assert_eq!(Aabb3::from_points(&[]), None);
+    }
+}
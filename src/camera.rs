@@ -0,0 +1,382 @@
+use crate::aabb::Aabb2;
+use crate::matrix::Mat4;
+use crate::vector::{Vec2, Vec3, Vec4};
+use crate::{trig, SignedFractional};
+use fixed_sqrt::FixedSqrt;
+
+/// Returns the position of an orbiting camera on a sphere of `radius` around `center`
+///
+/// `azimuth` is the angle in radians around the y axis, measured from the positive z axis towards the positive x
+/// axis. `elevation` is the angle in radians above the xz plane, so an elevation of `π/2` puts the camera directly
+/// above `center`.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::camera::orbit;
+/// # use skala_engine_numerics::vector::Vec3;
+/// # use skala_engine_numerics::SignedFractional;
+/// let position = orbit(Vec3::new(1, 2, 3), 5.into(), 0.into(), 0.into());
+///
+/// assert!(position.approx_eq(Vec3::new(1, 2, 8), SignedFractional::from_num(0.000_1)));
+/// ```
+#[must_use]
+pub fn orbit(
+    center: Vec3,
+    radius: SignedFractional,
+    azimuth: SignedFractional,
+    elevation: SignedFractional,
+) -> Vec3 {
+    let (azimuth_sin, azimuth_cos) = trig::sin_cos(azimuth);
+    let (elevation_sin, elevation_cos) = trig::sin_cos(elevation);
+
+    center
+        + Vec3::new(
+            elevation_cos * azimuth_sin,
+            elevation_sin,
+            elevation_cos * azimuth_cos,
+        ) * radius
+}
+
+/// Returns the angular radius in radians of a sphere of `radius` around `center` as seen from `eye`
+///
+/// This is `asin(radius / distance)`, half of the angle the sphere covers in the field of view. It shrinks as the
+/// sphere moves away, which makes it a good measure for picking a level of detail. When `eye` is inside of the sphere
+/// the sphere surrounds the viewer and `π/2` is returned.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::camera::apparent_radius;
+/// # use skala_engine_numerics::vector::Vec3;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_1);
+///
+/// // A sphere seen from twice its radius covers a 30° angular radius
+/// let angle = apparent_radius(Vec3::ZERO, Vec3::new(0, 0, 4), 2.into());
+///
+/// assert!((angle - SignedFractional::FRAC_PI_6).abs() < epsilon);
+/// assert_eq!(apparent_radius(Vec3::ZERO, Vec3::ZERO, 2.into()), SignedFractional::FRAC_PI_2);
+/// ```
+#[must_use]
+pub fn apparent_radius(eye: Vec3, center: Vec3, radius: SignedFractional) -> SignedFractional {
+    let distance = (center - eye).len();
+
+    if distance <= radius {
+        return SignedFractional::FRAC_PI_2;
+    }
+
+    // Length of the tangent line from the eye to the sphere, factored so it can't overflow before the square root
+    let tangent = ((distance - radius) * (distance + radius)).sqrt();
+
+    trig::atan2(radius, tangent)
+}
+
+/// Picks a level of detail for a sphere of `radius` around `center` seen from `eye`
+///
+/// `thresholds` are angular radii in radians ordered from largest to smallest. The result is the index of the first
+/// threshold the [`apparent_radius`] of the sphere reaches, so `0` is the most detailed level, or `thresholds.len()`
+/// when the sphere appears smaller than all of them.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::camera::select_lod;
+/// # use skala_engine_numerics::vector::Vec3;
+/// # use skala_engine_numerics::SignedFractional;
+/// let thresholds = [SignedFractional::from_num(0.5), SignedFractional::from_num(0.1)];
+///
+/// assert_eq!(select_lod(Vec3::ZERO, Vec3::new(0, 0, 2), 1.into(), &thresholds), 0);
+/// assert_eq!(select_lod(Vec3::ZERO, Vec3::new(0, 0, 5), 1.into(), &thresholds), 1);
+/// assert_eq!(select_lod(Vec3::ZERO, Vec3::new(0, 0, 50), 1.into(), &thresholds), 2);
+/// ```
+#[must_use]
+pub fn select_lod(
+    eye: Vec3,
+    center: Vec3,
+    radius: SignedFractional,
+    thresholds: &[SignedFractional],
+) -> usize {
+    let angle = apparent_radius(eye, center, radius);
+
+    thresholds
+        .iter()
+        .position(|&threshold| angle >= threshold)
+        .unwrap_or(thresholds.len())
+}
+
+/// Returns the rectangle in pixels covered by the sphere of `radius` around `center` when projected by `view_proj`
+/// onto a `viewport` of the given width and height, or `None` if the sphere is entirely behind the camera
+///
+/// The rectangle is conservative, it bounds the projected cube around the sphere and may be somewhat larger than
+/// the sphere itself. Pixel coordinates start at the top left corner of the viewport with y pointing down, parts of
+/// the sphere outside of the view extend the rectangle beyond the viewport. Spheres crossing the plane of the camera
+/// have no finite projection and cover the whole viewport.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::camera::project_sphere_bounds;
+/// # use skala_engine_numerics::matrix::Mat4;
+/// # use skala_engine_numerics::vector::{Vec2, Vec3, Vec4};
+/// // Looking down the negative z axis with a 90° field of view
+/// let view_proj = Mat4::from_cols(
+///     Mat4::IDENTITY.x_axis,
+///     Mat4::IDENTITY.y_axis,
+///     Vec4::new([0.into(), 0.into(), (-1).into(), (-1).into()]),
+///     Vec4::new([0.into(), 0.into(), (-2).into(), 0.into()]),
+/// );
+/// let viewport = Vec2::new(800.into(), 600.into());
+///
+/// let bounds = project_sphere_bounds(Vec3::new(0, 0, -10), 1.into(), view_proj, viewport).unwrap();
+///
+/// assert_eq!(bounds.center(), Vec2::new(400.into(), 300.into()));
+/// assert_eq!(project_sphere_bounds(Vec3::new(0, 0, 10), 1.into(), view_proj, viewport), None);
+/// ```
+#[must_use]
+pub fn project_sphere_bounds(
+    center: Vec3,
+    radius: SignedFractional,
+    view_proj: Mat4,
+    viewport: Vec2,
+) -> Option<Aabb2> {
+    // The clip space w is linear in the position, this is its gradient
+    let w_gradient = Vec3::new(
+        view_proj.x_axis[3],
+        view_proj.y_axis[3],
+        view_proj.z_axis[3],
+    );
+    let center_w = w_gradient.dot(center) + view_proj.w_axis[3];
+
+    if center_w + radius * w_gradient.len() <= SignedFractional::ZERO {
+        return None;
+    }
+
+    // The smallest w of the corners of the cube around the sphere
+    let corner_w =
+        center_w - radius * (w_gradient.x.abs() + w_gradient.y.abs() + w_gradient.z.abs());
+
+    if corner_w <= SignedFractional::ZERO {
+        return Some(Aabb2::new(Vec2::ZERO, viewport));
+    }
+
+    let corners: [Vec2; 8] = std::array::from_fn(|corner| {
+        let offset = |bit: usize| if corner & bit == 0 { -radius } else { radius };
+        let point = center + Vec3::new(offset(1), offset(2), offset(4));
+        let clip = view_proj * Vec4::new([point.x, point.y, point.z, SignedFractional::ONE]);
+        let (ndc_x, ndc_y) = (clip[0] / clip[3], clip[1] / clip[3]);
+
+        Vec2::new(
+            (ndc_x + SignedFractional::ONE) / 2 * viewport.x,
+            (SignedFractional::ONE - ndc_y) / 2 * viewport.y,
+        )
+    });
+
+    Aabb2::from_points(&corners)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aabb::Aabb2;
+    use crate::camera::{apparent_radius, orbit, project_sphere_bounds, select_lod};
+    use crate::matrix::Mat4;
+    use crate::vector::{Vec2, Vec3, Vec4};
+    use crate::SignedFractional;
+    use fixed_sqrt::FixedSqrt;
+
+    #[test]
+    fn orbit_known_angles() {
+        let center = Vec3::new(1, 2, 3);
+        let radius = SignedFractional::from_num(4);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        let cases = [
+            (
+                SignedFractional::ZERO,
+                SignedFractional::ZERO,
+                Vec3::new(1, 2, 7),
+            ),
+            (
+                SignedFractional::FRAC_PI_2,
+                SignedFractional::ZERO,
+                Vec3::new(5, 2, 3),
+            ),
+            (
+                SignedFractional::PI,
+                SignedFractional::ZERO,
+                Vec3::new(1, 2, -1),
+            ),
+            (
+                -SignedFractional::FRAC_PI_2,
+                SignedFractional::ZERO,
+                Vec3::new(-3, 2, 3),
+            ),
+            (
+                SignedFractional::ZERO,
+                SignedFractional::FRAC_PI_2,
+                Vec3::new(1, 6, 3),
+            ),
+            (
+                SignedFractional::PI,
+                -SignedFractional::FRAC_PI_2,
+                Vec3::new(1, -2, 3),
+            ),
+            (
+                SignedFractional::TAU + SignedFractional::FRAC_PI_2,
+                SignedFractional::ZERO,
+                Vec3::new(5, 2, 3),
+            ),
+        ];
+
+        for (azimuth, elevation, expected) in cases {
+            assert!(
+                orbit(center, radius, azimuth, elevation).approx_eq(expected, epsilon),
+                "azimuth {azimuth}, elevation {elevation}"
+            );
+        }
+    }
+
+    #[test]
+    fn orbit_diagonal_stays_on_sphere() {
+        let center = Vec3::new(-2, 0, 5);
+        let radius = SignedFractional::from_num(3);
+        let epsilon = SignedFractional::from_num(0.001);
+
+        let position = orbit(
+            center,
+            radius,
+            SignedFractional::FRAC_PI_4,
+            SignedFractional::FRAC_PI_6,
+        );
+        let offset = position - center;
+
+        assert!((offset.len() - radius).abs() < epsilon);
+        assert!((offset.y - SignedFractional::from_num(1.5)).abs() < epsilon);
+        assert!((offset.x - offset.z).abs() < epsilon);
+    }
+
+    #[test]
+    fn apparent_radius_shrinks_with_distance() {
+        let eye = Vec3::new(1, 1, 1);
+        let radius = SignedFractional::from_num(2);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        let near = apparent_radius(eye, Vec3::new(1, 1, 5), radius);
+        let far = apparent_radius(eye, Vec3::new(1, 1, 201), radius);
+
+        assert!((near - SignedFractional::FRAC_PI_6).abs() < epsilon);
+        assert!(far < near);
+        // Far away the angle approaches radius / distance
+        assert!((far - SignedFractional::from_num(0.01)).abs() < epsilon);
+    }
+
+    #[test]
+    fn apparent_radius_inside_sphere() {
+        let center = Vec3::new(3, 0, 0);
+        let radius = SignedFractional::from_num(5);
+
+        assert_eq!(
+            apparent_radius(Vec3::ZERO, center, radius),
+            SignedFractional::FRAC_PI_2
+        );
+        assert_eq!(
+            apparent_radius(Vec3::new(-2, 0, 0), center, radius),
+            SignedFractional::FRAC_PI_2
+        );
+    }
+
+    #[test]
+    fn lod_selection() {
+        let eye = Vec3::new(0, 2, 0);
+        let radius = SignedFractional::ONE;
+        let thresholds = [
+            SignedFractional::from_num(0.4),
+            SignedFractional::from_num(0.1),
+            SignedFractional::from_num(0.02),
+        ];
+
+        assert_eq!(select_lod(eye, Vec3::new(0, 2, 2), radius, &thresholds), 0);
+        assert_eq!(select_lod(eye, Vec3::new(0, 2, 5), radius, &thresholds), 1);
+        assert_eq!(select_lod(eye, Vec3::new(0, 2, 20), radius, &thresholds), 2);
+        assert_eq!(
+            select_lod(eye, Vec3::new(0, 2, 1_000), radius, &thresholds),
+            thresholds.len()
+        );
+
+        // Standing inside of the sphere always picks the most detailed level
+        assert_eq!(select_lod(eye, eye, radius, &thresholds), 0);
+        assert_eq!(select_lod(eye, Vec3::new(0, 2, 5), radius, &[]), 0);
+    }
+
+    /// A perspective projection looking down the negative z axis with a 90° field of view, a near plane at 1 and a
+    /// far plane at 100
+    fn perspective() -> Mat4 {
+        let depth_scale = SignedFractional::from_num(-101) / SignedFractional::from_num(99);
+        let depth_offset = SignedFractional::from_num(-200) / SignedFractional::from_num(99);
+
+        Mat4::from_cols(
+            Mat4::IDENTITY.x_axis,
+            Mat4::IDENTITY.y_axis,
+            Vec4::new([0.into(), 0.into(), depth_scale, (-1).into()]),
+            Vec4::new([0.into(), 0.into(), depth_offset, 0.into()]),
+        )
+    }
+
+    #[test]
+    fn centered_sphere_bounds() {
+        let viewport = Vec2::new(800.into(), 600.into());
+        let epsilon = SignedFractional::from_num(0.001);
+
+        let bounds =
+            project_sphere_bounds(Vec3::new(0, 0, -10), 1.into(), perspective(), viewport).unwrap();
+
+        assert!(bounds
+            .center()
+            .approx_eq(Vec2::new(400.into(), 300.into()), epsilon));
+
+        // The cube around the sphere reaches a ninth of the view at its closest face
+        let size = bounds.size();
+        assert!((size.x - SignedFractional::from_num(800) / 9).abs() < epsilon);
+        assert!((size.y - SignedFractional::from_num(600) / 9).abs() < epsilon);
+
+        // Still covering the true silhouette, which spans 1 / sqrt(99) of the view to either side
+        let silhouette = SignedFractional::from_num(400) / SignedFractional::from_num(99).sqrt();
+        let middle = SignedFractional::from_num(400);
+        assert!(bounds.contains(Vec2::new(middle + silhouette, 300.into())));
+        assert!(bounds.contains(Vec2::new(middle - silhouette, 300.into())));
+
+        // Further away the sphere covers less
+        let far =
+            project_sphere_bounds(Vec3::new(0, 0, -20), 1.into(), perspective(), viewport).unwrap();
+        assert!(far.size().x < size.x);
+        assert!(far.center().approx_eq(bounds.center(), epsilon));
+    }
+
+    #[test]
+    fn off_center_sphere_bounds() {
+        let viewport = Vec2::new(800.into(), 600.into());
+
+        let bounds =
+            project_sphere_bounds(Vec3::new(5, 3, -10), 1.into(), perspective(), viewport).unwrap();
+
+        // Right of and above the center, y points down in pixels
+        assert!(bounds.min.x > 400);
+        assert!(bounds.max.y < 300);
+    }
+
+    #[test]
+    fn sphere_behind_camera() {
+        let viewport = Vec2::new(800.into(), 600.into());
+
+        assert_eq!(
+            project_sphere_bounds(Vec3::new(0, 0, 10), 1.into(), perspective(), viewport),
+            None
+        );
+        assert_eq!(
+            project_sphere_bounds(Vec3::new(3, 0, 2), 2.into(), perspective(), viewport),
+            None
+        );
+
+        // Spheres around the camera cover everything
+        assert_eq!(
+            project_sphere_bounds(Vec3::new(0, 0, 1), 2.into(), perspective(), viewport),
+            Some(Aabb2::new(Vec2::ZERO, viewport))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::vector::Vec3;
+use crate::SignedFractional;
+
+/// A [PID controller](https://en.wikipedia.org/wiki/PID_controller) working on 3d errors
+///
+/// Feed it the difference between where something should be and where it is every step and apply the returned
+/// output (usually as a force or acceleration) to smoothly track the target.
+#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+pub struct VectorPid {
+    /// Gain applied to the current error
+    pub kp: SignedFractional,
+    /// Gain applied to the error accumulated over time
+    pub ki: SignedFractional,
+    /// Gain applied to the rate of change of the error
+    pub kd: SignedFractional,
+    accumulated_error: Vec3,
+    previous_error: Option<Vec3>,
+}
+
+impl VectorPid {
+    /// Creates a new controller from its proportional, integral and derivative gains
+    #[must_use]
+    pub const fn new(kp: SignedFractional, ki: SignedFractional, kd: SignedFractional) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            accumulated_error: Vec3::ZERO,
+            previous_error: None,
+        }
+    }
+
+    /// Advances the controller by `dt` seconds and returns the control output for `error`
+    ///
+    /// The derivative term is zero on the first update after creation or [`reset`](Self::reset) and whenever
+    /// `dt` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::control::VectorPid;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let mut pid = VectorPid::new(2.into(), 0.into(), 0.into());
+    ///
+    /// assert_eq!(pid.update(Vec3::new(1, 2, 3), 1.into()), Vec3::new(2, 4, 6));
+    /// ```
+    pub fn update(&mut self, error: Vec3, dt: SignedFractional) -> Vec3 {
+        self.accumulated_error += error * dt;
+
+        let rate_of_change = match self.previous_error {
+            Some(previous) if dt != SignedFractional::ZERO => (error - previous) / dt,
+            _ => Vec3::ZERO,
+        };
+        self.previous_error = Some(error);
+
+        error * self.kp + self.accumulated_error * self.ki + rate_of_change * self.kd
+    }
+
+    /// Forgets the accumulated and previous error
+    pub fn reset(&mut self) {
+        self.accumulated_error = Vec3::ZERO;
+        self.previous_error = None;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::control::VectorPid;
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn proportional_only() {
+        let mut pid = VectorPid::new(3.into(), 0.into(), 0.into());
+
+        assert_eq!(
+            pid.update(Vec3::new(1, -2, 0), 1.into()),
+            Vec3::new(3, -6, 0)
+        );
+        assert_eq!(
+            pid.update(Vec3::new(2, 4, 1), 1.into()),
+            Vec3::new(6, 12, 3)
+        );
+    }
+
+    #[test]
+    fn integral_accumulates() {
+        let mut pid = VectorPid::new(0.into(), 1.into(), 0.into());
+        let half = SignedFractional::from_num(0.5);
+
+        assert_eq!(pid.update(Vec3::new(2, 0, 0), half), Vec3::new(1, 0, 0));
+        assert_eq!(pid.update(Vec3::new(2, 0, 0), half), Vec3::new(2, 0, 0));
+
+        pid.reset();
+        assert_eq!(pid.update(Vec3::new(2, 0, 0), half), Vec3::new(1, 0, 0));
+    }
+
+    #[test]
+    fn derivative_reacts_to_change() {
+        let mut pid = VectorPid::new(0.into(), 0.into(), 1.into());
+
+        assert_eq!(pid.update(Vec3::new(4, 0, 0), 1.into()), Vec3::ZERO);
+        assert_eq!(
+            pid.update(Vec3::new(1, 0, 0), 1.into()),
+            Vec3::new(-3, 0, 0)
+        );
+    }
+}
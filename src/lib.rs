@@ -6,10 +6,54 @@
 //!
 //! SkalaEngineNumerics is a 'small' library of numeric types for use in [`SkalaEngine`](https://github.com/Brezak/SkalaEngine)
 
+/// Axis aligned bounding boxes
+pub mod aabb;
+/// Camera positioning helpers
+pub mod camera;
+/// Controllers for driving values towards targets
+pub mod control;
+/// Matrix types
+pub mod matrix;
+/// Mesh generation
+pub mod mesh;
+/// Rigid body physics helpers
+pub mod physics;
+/// Polygon utilities
+pub mod polygon;
+/// Polyline and path utilities
+pub mod polyline;
+/// Quaternions for 3d rotations
+pub mod quat;
+/// Rays and ray casting
+pub mod ray;
+/// Rectangles for 2d layout
+pub mod rect;
+/// Random sampling of shapes and directions
+pub mod sampling;
+/// Signed distance fields
+pub mod sdf;
+/// Lighting and shading helpers
+pub mod shading;
+/// Spatial partitioning structures
+pub mod spatial;
+/// Spline interpolation
+pub mod spline;
+/// Steering behaviours for moving agents
+pub mod steering;
+/// Recorded motion processing
+pub mod trajectory;
+/// 2d transforms for scene graphs
+pub mod transform;
+/// Triangle utilities
+pub mod triangle;
+/// Fixed point trigonometry
+pub mod trig;
 /// Vector types
 pub mod vector;
 
+pub use aabb::{Aabb2, Aabb3};
 use fixed::types::I32F32;
+pub use rect::Rect;
 pub use vector::{Vec2, Vec3};
 
 /// The current type backing all the numbers in the crate (may switch to a floats in the future)
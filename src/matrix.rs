@@ -0,0 +1,10 @@
+use crate::SignedFractional;
+
+mod mat3;
+mod mat4;
+
+pub use mat3::*;
+pub use mat4::*;
+
+/// Determinants smaller than this in magnitude, about one millionth, are treated as zero by the `inverse` methods
+const SINGULAR_DETERMINANT: SignedFractional = SignedFractional::from_bits(1 << 12);
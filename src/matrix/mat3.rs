@@ -0,0 +1,457 @@
+use crate::matrix::SINGULAR_DETERMINANT;
+use crate::vector::{Vec2, Vec3};
+use crate::{trig, SignedFractional};
+use std::ops::Mul;
+
+/// A 3x3 column major matrix
+///
+/// Describes linear transforms in 3d and affine transforms in 2d using homogeneous coordinates.
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Mat3 {
+    /// The first column, where the x axis gets mapped to
+    pub x_axis: Vec3,
+    /// The second column, where the y axis gets mapped to
+    pub y_axis: Vec3,
+    /// The third column, where the z axis gets mapped to
+    pub z_axis: Vec3,
+}
+
+impl Mat3 {
+    /// A [`Mat3`] with all of its elements set to zero
+    pub const ZERO: Self = Self::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+
+    /// The [`Mat3`] leaving every vector unchanged
+    pub const IDENTITY: Self = Self::from_cols(Vec3::X, Vec3::Y, Vec3::Z);
+
+    /// Creates a new [`Mat3`] from its columns
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let m = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+    ///
+    /// assert_eq!(m * Vec3::Y, Vec3::new(4, 5, 6));
+    /// ```
+    #[must_use]
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    /// Creates a [`Mat3`] scaling each axis by the matching component of `scale`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let m = Mat3::from_diagonal(Vec3::new(2, 3, 4));
+    ///
+    /// assert_eq!(m * Vec3::ONE, Vec3::new(2, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn from_diagonal(scale: Vec3) -> Self {
+        Self::from_cols(
+            Vec3::new(scale.x, 0, 0),
+            Vec3::new(0, scale.y, 0),
+            Vec3::new(0, 0, scale.z),
+        )
+    }
+
+    /// Returns the rows of this [`Mat3`] as vectors
+    fn rows(&self) -> [Vec3; 3] {
+        [
+            Vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            Vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            Vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+        ]
+    }
+
+    /// Returns this [`Mat3`] with its rows and columns swapped
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let m = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(4, 5, 6), Vec3::new(7, 8, 9));
+    ///
+    /// assert_eq!(m.transpose().x_axis, Vec3::new(1, 4, 7));
+    /// assert_eq!(m.transpose().transpose(), m);
+    /// ```
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let [x_axis, y_axis, z_axis] = self.rows();
+
+        Self::from_cols(x_axis, y_axis, z_axis)
+    }
+
+    /// Returns the determinant of this [`Mat3`]
+    ///
+    /// Its absolute value is the factor the matrix scales volumes by, it's negative when the matrix mirrors space and
+    /// zero when the matrix can't be inverted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// assert_eq!(Mat3::from_diagonal(Vec3::new(2, 3, 4)).determinant(), 24);
+    /// assert_eq!(Mat3::from_diagonal(Vec3::new(2, 0, 4)).determinant(), 0);
+    /// ```
+    #[must_use]
+    pub fn determinant(&self) -> SignedFractional {
+        self.x_axis.scalar_triple(self.y_axis, self.z_axis)
+    }
+
+    /// Returns the inverse of this [`Mat3`] or `None` if its determinant is zero
+    ///
+    /// Computed by cofactor expansion, the rows of the inverse are the cross products of pairs of columns divided by
+    /// the determinant. A determinant so close to zero that the inverse overflows also returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let m = Mat3::from_diagonal(Vec3::new(2, 4, 8));
+    /// let quarter = SignedFractional::from_num(0.25);
+    ///
+    /// assert_eq!(m.try_inverse(), Some(Mat3::from_diagonal(Vec3::new(SignedFractional::from_num(0.5), quarter, quarter / 2))));
+    /// assert_eq!(Mat3::ZERO.try_inverse(), None);
+    /// ```
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == SignedFractional::ZERO {
+            return None;
+        }
+
+        let rows = Self::from_cols(
+            self.y_axis.cross(self.z_axis),
+            self.z_axis.cross(self.x_axis),
+            self.x_axis.cross(self.y_axis),
+        );
+        let [x_axis, y_axis, z_axis] = rows.rows();
+        let divide = |v: Vec3| {
+            Some(Vec3::new(
+                v.x.checked_div(determinant)?,
+                v.y.checked_div(determinant)?,
+                v.z.checked_div(determinant)?,
+            ))
+        };
+
+        Some(Self::from_cols(
+            divide(x_axis)?,
+            divide(y_axis)?,
+            divide(z_axis)?,
+        ))
+    }
+
+    /// Returns the inverse of this [`Mat3`] or `None` if it's singular
+    ///
+    /// Unlike [`try_inverse`](Self::try_inverse) determinants within about one millionth of zero count as singular,
+    /// their inverses are dominated by rounding errors of the fixed point arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let m = Mat3::from_diagonal(Vec3::new(2, 4, 8));
+    /// let tiny = Mat3::from_diagonal(Vec3::new(1, 1, SignedFractional::from_num(0.000_000_1)));
+    ///
+    /// assert_eq!(m.inverse().map(|inverse| m * inverse), Some(Mat3::IDENTITY));
+    /// assert_eq!(tiny.inverse(), None);
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        if self.determinant().abs() < SINGULAR_DETERMINANT {
+            return None;
+        }
+
+        self.try_inverse()
+    }
+
+    /// Splits the 2d affine transform of this [`Mat3`] into its translation, rotation in radians and scale
+    ///
+    /// Composing them back as translation * rotation * scale gives this matrix. A mirroring transform is
+    /// reported as a negative y scale. The transform can't be represented this way if it shears,
+    /// use [`has_shear`](Self::has_shear) to detect that, the returned y scale then only preserves the area.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::{Vec2, Vec3};
+    /// # use skala_engine_numerics::SignedFractional;
+    /// // A quarter turn, scaled by 2 on x and moved by (5, 6)
+    /// let m = Mat3::from_cols(Vec3::new(0, 2, 0), Vec3::new(-1, 0, 0), Vec3::new(5, 6, 1));
+    /// let (translation, rotation, scale) = m.to_translation_rotation_scale();
+    ///
+    /// assert_eq!(translation, Vec2::new(5.into(), 6.into()));
+    /// assert!((rotation - SignedFractional::FRAC_PI_2).abs() < SignedFractional::from_num(0.000_001));
+    /// assert_eq!(scale, Vec2::new(2.into(), 1.into()));
+    /// ```
+    #[must_use]
+    pub fn to_translation_rotation_scale(&self) -> (Vec2, SignedFractional, Vec2) {
+        let translation = Vec2::new(self.z_axis.x, self.z_axis.y);
+        let x_axis = Vec2::new(self.x_axis.x, self.x_axis.y);
+        let y_axis = Vec2::new(self.y_axis.x, self.y_axis.y);
+        let scale_x = x_axis.len();
+
+        if scale_x == SignedFractional::ZERO {
+            // The x axis collapsed, so the rotation can only be read from the y axis
+            let rotation = trig::atan2(-y_axis.x, y_axis.y);
+
+            return (translation, rotation, Vec2::new(scale_x, y_axis.len()));
+        }
+
+        // The signed area spanned by the axes carries the mirroring
+        let scale_y = x_axis.cross(y_axis) / scale_x;
+
+        (
+            translation,
+            trig::atan2(x_axis.y, x_axis.x),
+            Vec2::new(scale_x, scale_y),
+        )
+    }
+
+    /// Returns `true` if the 2d affine transform of this [`Mat3`] shears, meaning its x and y axes aren't perpendicular
+    ///
+    /// Axes within about a thousandth of a radian of perpendicular are accepted to absorb rounding errors.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat3;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let sheared = Mat3::from_cols(Vec3::X, Vec3::new(1, 1, 0), Vec3::Z);
+    ///
+    /// assert!(sheared.has_shear());
+    /// assert!(!Mat3::from_diagonal(Vec3::new(2, -3, 1)).has_shear());
+    /// ```
+    #[must_use]
+    pub fn has_shear(&self) -> bool {
+        let x_axis = Vec2::new(self.x_axis.x, self.x_axis.y);
+        let y_axis = Vec2::new(self.y_axis.x, self.y_axis.y);
+
+        x_axis.dot(y_axis).abs() > x_axis.len() * y_axis.len() * SignedFractional::from_num(0.001)
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(self * rhs.x_axis, self * rhs.y_axis, self * rhs.z_axis)
+    }
+}
+
+impl Mul<SignedFractional> for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: SignedFractional) -> Self::Output {
+        Self::from_cols(self.x_axis * rhs, self.y_axis * rhs, self.z_axis * rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat3;
+    use crate::vector::{Vec2, Vec3};
+    use crate::{trig, SignedFractional};
+
+    fn approx_eq(a: Mat3, b: Mat3, epsilon: SignedFractional) -> bool {
+        a.x_axis.approx_eq(b.x_axis, epsilon)
+            && a.y_axis.approx_eq(b.y_axis, epsilon)
+            && a.z_axis.approx_eq(b.z_axis, epsilon)
+    }
+
+    #[test]
+    fn identity() {
+        let v = Vec3::new(3, -7, SignedFractional::from_num(0.5));
+        let m = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(0, 1, 4), Vec3::new(5, 6, 0));
+
+        assert_eq!(Mat3::IDENTITY * v, v);
+        assert_eq!(Mat3::IDENTITY * m, m);
+        assert_eq!(m * Mat3::IDENTITY, m);
+        assert_eq!(Mat3::default(), Mat3::IDENTITY);
+        assert_eq!(Mat3::IDENTITY.determinant(), 1);
+        assert_eq!(Mat3::IDENTITY.try_inverse(), Some(Mat3::IDENTITY));
+    }
+
+    #[test]
+    fn composition() {
+        // A quarter turn around z followed by scaling x by 2
+        let turn = Mat3::from_cols(Vec3::Y, -Vec3::X, Vec3::Z);
+        let scale = Mat3::from_diagonal(Vec3::new(2, 1, 1));
+        let v = Vec3::new(1, 2, 3);
+
+        assert_eq!((scale * turn) * v, scale * (turn * v));
+        assert_eq!((scale * turn) * v, Vec3::new(-4, 1, 3));
+        assert_eq!((turn * scale).determinant(), 2);
+        assert_eq!((turn * SignedFractional::from_num(2)).determinant(), 8);
+    }
+
+    #[test]
+    fn transposition() {
+        let m = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(0, 1, 4), Vec3::new(5, 6, 0));
+        let t = m.transpose();
+
+        assert_eq!(t.x_axis, Vec3::new(1, 0, 5));
+        assert_eq!(t.y_axis, Vec3::new(2, 1, 6));
+        assert_eq!(t.z_axis, Vec3::new(3, 4, 0));
+        assert_eq!(t.determinant(), m.determinant());
+    }
+
+    #[test]
+    fn inversion_round_trip() {
+        let m = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(0, 1, 4), Vec3::new(5, 6, 0));
+        let epsilon = SignedFractional::from_num(0.000_01);
+
+        assert_eq!(m.determinant(), 1);
+
+        let inverse = m.try_inverse().unwrap();
+        assert_eq!(
+            inverse,
+            Mat3::from_cols(
+                Vec3::new(-24, 18, 5),
+                Vec3::new(20, -15, -4),
+                Vec3::new(-5, 4, 1)
+            )
+        );
+        assert_eq!(m * inverse, Mat3::IDENTITY);
+        assert_eq!(inverse * m, Mat3::IDENTITY);
+
+        let skewed = Mat3::from_cols(Vec3::new(2, 0, 1), Vec3::new(1, 3, 0), Vec3::new(0, 1, 4));
+        let inverse = skewed.try_inverse().unwrap();
+        let v = Vec3::new(7, -2, 5);
+
+        assert!(approx_eq(skewed * inverse, Mat3::IDENTITY, epsilon));
+        assert!((inverse * (skewed * v)).approx_eq(v, epsilon));
+    }
+
+    #[test]
+    fn singular_matrices() {
+        let flat = Mat3::from_cols(Vec3::new(1, 2, 3), Vec3::new(2, 4, 6), Vec3::Z);
+
+        assert_eq!(flat.determinant(), 0);
+        assert_eq!(flat.try_inverse(), None);
+        assert_eq!(Mat3::ZERO.try_inverse(), None);
+    }
+
+    #[test]
+    fn nearly_singular_matrices() {
+        let nearly_flat =
+            Mat3::from_cols(Vec3::X, Vec3::new(1, SignedFractional::DELTA, 0), Vec3::Z);
+
+        assert_eq!(nearly_flat.determinant(), SignedFractional::DELTA);
+        assert_eq!(nearly_flat.try_inverse(), None);
+    }
+
+    #[test]
+    fn tolerant_inversion() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let skewed = Mat3::from_cols(Vec3::new(2, 0, 1), Vec3::new(1, 3, 0), Vec3::new(0, 1, 4));
+        let tiny = SignedFractional::from_num(0.000_000_5);
+        let nearly_flat = Mat3::from_diagonal(Vec3::new(1, 1, tiny));
+
+        assert!(approx_eq(
+            skewed * skewed.inverse().unwrap(),
+            Mat3::IDENTITY,
+            epsilon
+        ));
+        assert_eq!(skewed.inverse(), skewed.try_inverse());
+
+        // Representable but too close to singular to be trusted
+        assert!(nearly_flat.try_inverse().is_some());
+        assert_eq!(nearly_flat.inverse(), None);
+        assert_eq!(Mat3::ZERO.inverse(), None);
+    }
+
+    #[test]
+    fn translation_rotation_scale_round_trip() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let compose = |translation: Vec2, rotation: SignedFractional, scale: Vec2| {
+            let (sin, cos) = trig::sin_cos(rotation);
+            let translate =
+                Mat3::from_cols(Vec3::X, Vec3::Y, Vec3::new(translation.x, translation.y, 1));
+            let rotate = Mat3::from_cols(Vec3::new(cos, sin, 0), Vec3::new(-sin, cos, 0), Vec3::Z);
+
+            translate * rotate * Mat3::from_diagonal(Vec3::new(scale.x, scale.y, 1))
+        };
+
+        for (translation, rotation, scale) in [
+            (
+                Vec2::new(3.into(), (-4).into()),
+                SignedFractional::from_num(0.7),
+                Vec2::new(2.into(), SignedFractional::from_num(0.5)),
+            ),
+            (
+                Vec2::new((-10).into(), 2.into()),
+                SignedFractional::from_num(-2.5),
+                Vec2::new(1.into(), 3.into()),
+            ),
+            // Mirrored along y
+            (
+                Vec2::new(1.into(), 1.into()),
+                SignedFractional::from_num(1.2),
+                Vec2::new(2.into(), (-3).into()),
+            ),
+        ] {
+            let m = compose(translation, rotation, scale);
+            let (t, r, s) = m.to_translation_rotation_scale();
+
+            assert!(!m.has_shear());
+            assert_eq!(t, translation);
+            assert!((r - rotation).abs() < epsilon);
+            assert!(s.approx_eq(scale, epsilon));
+        }
+
+        // Mirroring x is the same transform as mirroring y after a half turn
+        let mirrored_x = compose(
+            Vec2::ZERO,
+            SignedFractional::from_num(0.3),
+            Vec2::new((-2).into(), 1.into()),
+        );
+        let (t, r, s) = mirrored_x.to_translation_rotation_scale();
+
+        assert!(s.y < 0);
+        assert!(approx_eq(compose(t, r, s), mirrored_x, epsilon));
+    }
+
+    #[test]
+    fn shear_detection() {
+        let sheared = Mat3::from_cols(Vec3::new(2, 0, 0), Vec3::new(1, 1, 0), Vec3::new(4, 5, 1));
+        let (translation, rotation, scale) = sheared.to_translation_rotation_scale();
+
+        assert!(sheared.has_shear());
+        assert_eq!(translation, Vec2::new(4.into(), 5.into()));
+        assert_eq!(rotation, 0);
+        // The area of the sheared transform is kept
+        assert_eq!(scale.x * scale.y, sheared.determinant());
+
+        let collapsed = Mat3::from_cols(Vec3::ZERO, Vec3::new(-3, 0, 0), Vec3::Z);
+        let (_, rotation, scale) = collapsed.to_translation_rotation_scale();
+
+        assert!(
+            (rotation - SignedFractional::FRAC_PI_2).abs() < SignedFractional::from_num(0.000_001)
+        );
+        assert_eq!(scale, Vec2::new(0.into(), 3.into()));
+    }
+}
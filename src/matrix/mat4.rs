@@ -0,0 +1,476 @@
+use crate::matrix::SINGULAR_DETERMINANT;
+use crate::vector::{Vec3, Vec4};
+use crate::SignedFractional;
+use std::ops::Mul;
+
+/// A 4x4 column major matrix
+///
+/// Describes affine and projective transforms in 3d using homogeneous coordinates, points have a `w` of one and
+/// directions a `w` of zero.
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Mat4 {
+    /// The first column, where the x axis gets mapped to
+    pub x_axis: Vec4,
+    /// The second column, where the y axis gets mapped to
+    pub y_axis: Vec4,
+    /// The third column, where the z axis gets mapped to
+    pub z_axis: Vec4,
+    /// The fourth column, holding the translation of affine transforms
+    pub w_axis: Vec4,
+}
+
+impl Mat4 {
+    /// A [`Mat4`] with all of its elements set to zero
+    pub const ZERO: Self = Self::from_cols(Vec4::ZERO, Vec4::ZERO, Vec4::ZERO, Vec4::ZERO);
+
+    /// The [`Mat4`] leaving every vector unchanged
+    pub const IDENTITY: Self = Self::from_cols(
+        Vec4::new([
+            SignedFractional::ONE,
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+        ]),
+        Vec4::new([
+            SignedFractional::ZERO,
+            SignedFractional::ONE,
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+        ]),
+        Vec4::new([
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+            SignedFractional::ONE,
+            SignedFractional::ZERO,
+        ]),
+        Vec4::new([
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+            SignedFractional::ZERO,
+            SignedFractional::ONE,
+        ]),
+    );
+
+    /// Creates a new [`Mat4`] from its columns
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let w_axis = Vec4::new([1.into(), 2.into(), 3.into(), 1.into()]);
+    /// let m = Mat4::from_cols(Mat4::IDENTITY.x_axis, Mat4::IDENTITY.y_axis, Mat4::IDENTITY.z_axis, w_axis);
+    ///
+    /// assert_eq!(m * Vec4::new([0.into(), 0.into(), 0.into(), 1.into()]), w_axis);
+    /// ```
+    #[must_use]
+    pub const fn from_cols(x_axis: Vec4, y_axis: Vec4, z_axis: Vec4, w_axis: Vec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Creates a [`Mat4`] moving points by `translation` and leaving directions unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::{Vec3, Vec4};
+    /// let m = Mat4::from_translation(Vec3::new(1, 2, 3));
+    /// let point = Vec4::new([1.into(), 1.into(), 1.into(), 1.into()]);
+    ///
+    /// assert_eq!(m * point, Vec4::new([2.into(), 3.into(), 4.into(), 1.into()]));
+    /// ```
+    #[must_use]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            w_axis: Vec4::new([
+                translation.x,
+                translation.y,
+                translation.z,
+                SignedFractional::ONE,
+            ]),
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Creates a [`Mat4`] scaling each axis by the matching component of `scale`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::{Vec3, Vec4};
+    /// let m = Mat4::from_scale(Vec3::new(2, 3, 4));
+    ///
+    /// assert_eq!(m * Vec4::ONE, Vec4::new([2.into(), 3.into(), 4.into(), 1.into()]));
+    /// ```
+    #[must_use]
+    pub fn from_scale(scale: Vec3) -> Self {
+        let mut m = Self::IDENTITY;
+        m.x_axis[0] = scale.x;
+        m.y_axis[1] = scale.y;
+        m.z_axis[2] = scale.z;
+
+        m
+    }
+
+    /// Returns the columns of this [`Mat4`] as an array
+    fn cols(&self) -> [Vec4; 4] {
+        [self.x_axis, self.y_axis, self.z_axis, self.w_axis]
+    }
+
+    /// Returns this [`Mat4`] with its rows and columns swapped
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::{Vec3, Vec4};
+    /// let m = Mat4::from_translation(Vec3::new(1, 2, 3));
+    ///
+    /// assert_eq!(m.transpose().x_axis, Vec4::new([1.into(), 0.into(), 0.into(), 1.into()]));
+    /// assert_eq!(m.transpose().transpose(), m);
+    /// ```
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let cols = self.cols();
+        let row = |i: usize| Vec4::new(cols.map(|col| col[i]));
+
+        Self::from_cols(row(0), row(1), row(2), row(3))
+    }
+
+    /// Returns the determinants of the 2x2 minors of the first two and the last two columns
+    fn minors(&self) -> ([SignedFractional; 6], [SignedFractional; 6]) {
+        let [a, b, c, d] = self.cols();
+        let minor = |x: Vec4, y: Vec4, i: usize, j: usize| x[i] * y[j] - y[i] * x[j];
+
+        (
+            [
+                minor(a, b, 0, 1),
+                minor(a, b, 0, 2),
+                minor(a, b, 0, 3),
+                minor(a, b, 1, 2),
+                minor(a, b, 1, 3),
+                minor(a, b, 2, 3),
+            ],
+            [
+                minor(c, d, 0, 1),
+                minor(c, d, 0, 2),
+                minor(c, d, 0, 3),
+                minor(c, d, 1, 2),
+                minor(c, d, 1, 3),
+                minor(c, d, 2, 3),
+            ],
+        )
+    }
+
+    /// Returns the determinant of this [`Mat4`]
+    ///
+    /// Its absolute value is the factor the matrix scales volumes by, it's negative when the matrix mirrors space and
+    /// zero when the matrix can't be inverted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// assert_eq!(Mat4::from_scale(Vec3::new(2, 3, 4)).determinant(), 24);
+    /// assert_eq!(Mat4::from_translation(Vec3::new(2, 3, 4)).determinant(), 1);
+    /// ```
+    #[must_use]
+    pub fn determinant(&self) -> SignedFractional {
+        let (s, c) = self.minors();
+
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse of this [`Mat4`] or `None` if its determinant is zero
+    ///
+    /// Computed by cofactor expansion, the cofactors are built from the 2x2 minors of the first two and the last two
+    /// columns which are shared with the determinant. A determinant so close to zero that the inverse overflows also
+    /// returns `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let m = Mat4::from_translation(Vec3::new(1, 2, 3));
+    ///
+    /// assert_eq!(m.try_inverse(), Some(Mat4::from_translation(Vec3::new(-1, -2, -3))));
+    /// assert_eq!(Mat4::ZERO.try_inverse(), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant == SignedFractional::ZERO {
+            return None;
+        }
+
+        let [a, b, c, d] = self.cols();
+        let (s, t) = self.minors();
+        let col = |mut x: [SignedFractional; 4]| {
+            for element in &mut x {
+                *element = element.checked_div(determinant)?;
+            }
+
+            Some(Vec4::new(x))
+        };
+
+        Some(Self::from_cols(
+            col([
+                b[1] * t[5] - b[2] * t[4] + b[3] * t[3],
+                -a[1] * t[5] + a[2] * t[4] - a[3] * t[3],
+                d[1] * s[5] - d[2] * s[4] + d[3] * s[3],
+                -c[1] * s[5] + c[2] * s[4] - c[3] * s[3],
+            ])?,
+            col([
+                -b[0] * t[5] + b[2] * t[2] - b[3] * t[1],
+                a[0] * t[5] - a[2] * t[2] + a[3] * t[1],
+                -d[0] * s[5] + d[2] * s[2] - d[3] * s[1],
+                c[0] * s[5] - c[2] * s[2] + c[3] * s[1],
+            ])?,
+            col([
+                b[0] * t[4] - b[1] * t[2] + b[3] * t[0],
+                -a[0] * t[4] + a[1] * t[2] - a[3] * t[0],
+                d[0] * s[4] - d[1] * s[2] + d[3] * s[0],
+                -c[0] * s[4] + c[1] * s[2] - c[3] * s[0],
+            ])?,
+            col([
+                -b[0] * t[3] + b[1] * t[1] - b[2] * t[0],
+                a[0] * t[3] - a[1] * t[1] + a[2] * t[0],
+                -d[0] * s[3] + d[1] * s[1] - d[2] * s[0],
+                c[0] * s[3] - c[1] * s[1] + c[2] * s[0],
+            ])?,
+        ))
+    }
+
+    /// Returns the inverse of this [`Mat4`] or `None` if it's singular
+    ///
+    /// Unlike [`try_inverse`](Self::try_inverse) determinants within about one millionth of zero count as singular,
+    /// their inverses are dominated by rounding errors of the fixed point arithmetic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::matrix::Mat4;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let m = Mat4::from_scale(Vec3::new(2, 4, 8));
+    /// let tiny = Mat4::from_scale(Vec3::new(1, 1, SignedFractional::from_num(0.000_000_1)));
+    ///
+    /// assert_eq!(m.inverse().map(|inverse| m * inverse), Some(Mat4::IDENTITY));
+    /// assert_eq!(tiny.inverse(), None);
+    /// ```
+    #[must_use]
+    pub fn inverse(&self) -> Option<Self> {
+        if self.determinant().abs() < SINGULAR_DETERMINANT {
+            return None;
+        }
+
+        self.try_inverse()
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        self.x_axis * rhs[0] + self.y_axis * rhs[1] + self.z_axis * rhs[2] + self.w_axis * rhs[3]
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_cols(
+            self * rhs.x_axis,
+            self * rhs.y_axis,
+            self * rhs.z_axis,
+            self * rhs.w_axis,
+        )
+    }
+}
+
+impl Mul<SignedFractional> for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: SignedFractional) -> Self::Output {
+        Self::from_cols(
+            self.x_axis * rhs,
+            self.y_axis * rhs,
+            self.z_axis * rhs,
+            self.w_axis * rhs,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::vector::{Vec3, Vec4};
+    use crate::SignedFractional;
+
+    fn vec4(x: i32, y: i32, z: i32, w: i32) -> Vec4 {
+        Vec4::new([x.into(), y.into(), z.into(), w.into()])
+    }
+
+    fn approx_eq(a: Mat4, b: Mat4, epsilon: SignedFractional) -> bool {
+        a.x_axis.approx_eq(b.x_axis, epsilon)
+            && a.y_axis.approx_eq(b.y_axis, epsilon)
+            && a.z_axis.approx_eq(b.z_axis, epsilon)
+            && a.w_axis.approx_eq(b.w_axis, epsilon)
+    }
+
+    #[test]
+    fn identity() {
+        let v = vec4(3, -7, 2, 1);
+        let m = Mat4::from_cols(
+            vec4(1, 2, 3, 0),
+            vec4(0, 1, 4, 2),
+            vec4(5, 6, 0, 1),
+            vec4(1, 0, 0, 1),
+        );
+
+        assert_eq!(Mat4::IDENTITY * v, v);
+        assert_eq!(Mat4::IDENTITY * m, m);
+        assert_eq!(m * Mat4::IDENTITY, m);
+        assert_eq!(Mat4::default(), Mat4::IDENTITY);
+        assert_eq!(Mat4::IDENTITY.transpose(), Mat4::IDENTITY);
+        assert_eq!(Mat4::IDENTITY.determinant(), 1);
+        assert_eq!(Mat4::IDENTITY.try_inverse(), Some(Mat4::IDENTITY));
+    }
+
+    #[test]
+    fn translation_moves_points() {
+        let m = Mat4::from_translation(Vec3::new(4, -2, SignedFractional::from_num(0.5)));
+
+        let point = vec4(1, 2, 3, 1);
+        let direction = vec4(1, 2, 3, 0);
+
+        assert_eq!(
+            m * point,
+            Vec4::new([
+                5.into(),
+                0.into(),
+                SignedFractional::from_num(3.5),
+                1.into()
+            ])
+        );
+        assert_eq!(m * direction, direction);
+
+        // Scaling first and translating second
+        let transform = m * Mat4::from_scale(Vec3::new(2, 2, 2));
+        assert_eq!(
+            transform * point,
+            Vec4::new([
+                6.into(),
+                2.into(),
+                SignedFractional::from_num(6.5),
+                1.into()
+            ])
+        );
+        assert_eq!(transform.determinant(), 8);
+    }
+
+    #[test]
+    fn transposition() {
+        let m = Mat4::from_cols(
+            vec4(1, 2, 3, 0),
+            vec4(0, 1, 4, 2),
+            vec4(5, 6, 0, 1),
+            vec4(1, 0, 0, 1),
+        );
+        let t = m.transpose();
+
+        assert_eq!(t.x_axis, vec4(1, 0, 5, 1));
+        assert_eq!(t.w_axis, vec4(0, 2, 1, 1));
+        assert_eq!(t.transpose(), m);
+        assert_eq!(t.determinant(), m.determinant());
+        assert_eq!(
+            (m * SignedFractional::from_num(2)).determinant(),
+            m.determinant() * 16
+        );
+    }
+
+    #[test]
+    fn inversion_round_trip() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let m = Mat4::from_cols(
+            vec4(2, 0, 1, 0),
+            vec4(1, 3, 0, 1),
+            vec4(0, 1, 4, 0),
+            vec4(1, -2, 3, 1),
+        );
+        let v = vec4(7, -2, 5, 1);
+
+        let inverse = m.try_inverse().unwrap();
+
+        assert!(approx_eq(m * inverse, Mat4::IDENTITY, epsilon));
+        assert!(approx_eq(inverse * m, Mat4::IDENTITY, epsilon));
+        assert!((inverse * (m * v)).approx_eq(v, epsilon));
+
+        let scale = Mat4::from_scale(Vec3::new(2, 4, 8));
+        let quarter = SignedFractional::from_num(0.25);
+        assert_eq!(
+            scale.try_inverse(),
+            Some(Mat4::from_scale(Vec3::new(
+                SignedFractional::from_num(0.5),
+                quarter,
+                quarter / 2
+            )))
+        );
+    }
+
+    #[test]
+    fn singular_matrices() {
+        let flat = Mat4::from_cols(
+            vec4(1, 2, 3, 4),
+            vec4(2, 4, 6, 8),
+            vec4(0, 0, 1, 0),
+            vec4(0, 1, 0, 0),
+        );
+
+        assert_eq!(flat.determinant(), 0);
+        assert_eq!(flat.try_inverse(), None);
+        assert_eq!(Mat4::ZERO.try_inverse(), None);
+        assert_eq!(Mat4::from_scale(Vec3::new(1, 0, 1)).try_inverse(), None);
+    }
+
+    #[test]
+    fn nearly_singular_matrices() {
+        let mut nearly_flat = Mat4::IDENTITY;
+        nearly_flat.y_axis = Vec4::new([1.into(), SignedFractional::DELTA, 0.into(), 0.into()]);
+
+        assert_eq!(nearly_flat.determinant(), SignedFractional::DELTA);
+        assert_eq!(nearly_flat.try_inverse(), None);
+    }
+
+    #[test]
+    fn tolerant_inversion() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let m = Mat4::from_cols(
+            vec4(2, 0, 1, 0),
+            vec4(1, 3, 0, 1),
+            vec4(0, 1, 4, 0),
+            vec4(1, -2, 3, 1),
+        );
+        let tiny = SignedFractional::from_num(0.000_000_5);
+        let nearly_flat = Mat4::from_scale(Vec3::new(1, tiny, 1));
+
+        assert!(approx_eq(m * m.inverse().unwrap(), Mat4::IDENTITY, epsilon));
+        assert_eq!(m.inverse(), m.try_inverse());
+
+        // Representable but too close to singular to be trusted
+        assert!(nearly_flat.try_inverse().is_some());
+        assert_eq!(nearly_flat.inverse(), None);
+        assert_eq!(Mat4::ZERO.inverse(), None);
+    }
+}
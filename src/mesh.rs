@@ -0,0 +1,469 @@
+use crate::matrix::Mat4;
+use crate::polyline::parallel_transport_frames;
+use crate::vector::{Vec2, Vec3, Vec4};
+use crate::{trig, SignedFractional};
+
+/// Builds a tube of `radius` around `path` with a cross section of `segments` vertices and returns its vertices and
+/// triangles
+///
+/// The cross sections are oriented with [`parallel_transport_frames`] so the tube doesn't twist. Each point of the
+/// path gets a ring of `segments` vertices stored one after another, the triangles index into the vertices and wind
+/// counterclockwise when seen from outside of the tube. The ends of the tube are left open. Returns an empty mesh
+/// when every point of the path is the same.
+///
+/// # Panics
+/// When `segments` is less than three
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::mesh::tube_mesh;
+/// # use skala_engine_numerics::vector::Vec3;
+/// let path = [Vec3::new(0, 0, 0), Vec3::new(0, 0, 5)];
+///
+/// let (vertices, triangles) = tube_mesh(&path, 1.into(), 8);
+///
+/// assert_eq!(vertices.len(), 16);
+/// assert_eq!(triangles.len(), 16);
+/// ```
+#[must_use]
+pub fn tube_mesh(
+    path: &[Vec3],
+    radius: SignedFractional,
+    segments: usize,
+) -> (Vec<Vec3>, Vec<[usize; 3]>) {
+    assert!(segments >= 3, "Tube needs at least three segments");
+
+    let frames = parallel_transport_frames(path);
+    let ring: Vec<(SignedFractional, SignedFractional)> = (0..segments)
+        .map(|segment| {
+            trig::sin_cos(
+                SignedFractional::TAU * SignedFractional::from_num(segment)
+                    / SignedFractional::from_num(segments),
+            )
+        })
+        .collect();
+
+    let vertices = path
+        .iter()
+        .zip(&frames)
+        .flat_map(|(center, (right, up, _))| {
+            ring.iter()
+                .map(move |(sin, cos)| *center + (*right * *cos + *up * *sin) * radius)
+        })
+        .collect();
+
+    let triangles = (1..frames.len())
+        .flat_map(|ring_index| {
+            let previous = (ring_index - 1) * segments;
+            let current = ring_index * segments;
+
+            (0..segments).flat_map(move |segment| {
+                let next = (segment + 1) % segments;
+
+                [
+                    [previous + segment, previous + next, current + next],
+                    [previous + segment, current + next, current + segment],
+                ]
+            })
+        })
+        .collect();
+
+    (vertices, triangles)
+}
+
+/// Computes per vertex tangents pointing along the direction of increasing u texture coordinate, as used for normal
+/// mapping
+///
+/// Uses Lengyel's method, the tangents of all triangles sharing a vertex are summed and then made perpendicular to
+/// the vertex normal derived from those triangles before being normalized. Vertices not used by any triangle with a
+/// proper uv mapping get a zero tangent.
+///
+/// # Panics
+/// When `uvs` and `positions` have different lengths or a triangle indexes past their end
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::mesh::compute_tangents;
+/// # use skala_engine_numerics::{Vec2, Vec3};
+/// let positions = [Vec3::new(0, 0, 0), Vec3::new(0, 2, 0), Vec3::new(-2, 0, 0)];
+/// // The texture's u axis runs along the world's y axis
+/// let uvs = [Vec2::ZERO, Vec2::X, Vec2::Y];
+///
+/// let tangents = compute_tangents(&positions, &uvs, &[[0, 1, 2]]);
+///
+/// assert!(tangents.iter().all(|tangent| *tangent == Vec3::Y));
+/// ```
+#[must_use]
+pub fn compute_tangents(positions: &[Vec3], uvs: &[Vec2], indices: &[[usize; 3]]) -> Vec<Vec3> {
+    assert_eq!(
+        positions.len(),
+        uvs.len(),
+        "Every position needs exactly one uv coordinate"
+    );
+
+    let mut tangents = vec![Vec3::ZERO; positions.len()];
+    let mut normals = vec![Vec3::ZERO; positions.len()];
+
+    for [a, b, c] in indices.iter().copied() {
+        let edge1 = positions[b] - positions[a];
+        let edge2 = positions[c] - positions[a];
+        let delta_uv1 = uvs[b] - uvs[a];
+        let delta_uv2 = uvs[c] - uvs[a];
+
+        let determinant = delta_uv1.cross(delta_uv2);
+
+        if determinant == SignedFractional::ZERO {
+            continue;
+        }
+
+        let tangent = (edge1 * delta_uv2.y - edge2 * delta_uv1.y) / determinant;
+        let normal = edge1.cross(edge2);
+
+        for vertex in [a, b, c] {
+            tangents[vertex] += tangent;
+            normals[vertex] += normal;
+        }
+    }
+
+    tangents
+        .into_iter()
+        .zip(normals)
+        .map(|(tangent, normal)| {
+            // Gram-Schmidt orthogonalization against the normal
+            let normal = normal.normalize_or_zero();
+
+            (tangent - normal * normal.dot(tangent)).normalize_or_zero()
+        })
+        .collect()
+}
+
+/// Projects `point` onto the plane through `origin` spanned by `u_axis` and `v_axis` and returns its texture
+/// coordinates
+///
+/// Each coordinate is the dot product of the axis with the offset of `point` from `origin`, so unit axes measure
+/// the coordinates in world units while shorter axes stretch the texture. Used for planar and box mapping.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::mesh::planar_uv;
+/// # use skala_engine_numerics::{Vec2, Vec3};
+/// let uv = planar_uv(Vec3::new(3, 5, 7), Vec3::new(1, 1, 1), Vec3::X, Vec3::Y);
+///
+/// assert_eq!(uv, Vec2::new(2.into(), 4.into()));
+/// ```
+#[must_use]
+pub fn planar_uv(point: Vec3, origin: Vec3, u_axis: Vec3, v_axis: Vec3) -> Vec2 {
+    let offset = point - origin;
+
+    Vec2::new(offset.dot(u_axis), offset.dot(v_axis))
+}
+
+/// Evaluates blend shapes, writing `base + Σ weight * (target - base)` for every vertex into `out`
+///
+/// Each entry of `targets` is a morph target holding one position per vertex of `base` and its weight. A weight of
+/// zero leaves the vertices at `base` and a weight of one moves them onto the target, other weights interpolate or
+/// extrapolate and the offsets of all targets add up.
+///
+/// # Panics
+/// When `out` or any of the targets has a different length than `base`
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::mesh::morph;
+/// # use skala_engine_numerics::vector::Vec3;
+/// # use skala_engine_numerics::SignedFractional;
+/// let base = [Vec3::new(0, 0, 0), Vec3::new(2, 0, 0)];
+/// let smile = [Vec3::new(0, 4, 0), Vec3::new(2, 4, 0)];
+/// let mut out = [Vec3::ZERO; 2];
+///
+/// morph(&base, &[(&smile, SignedFractional::from_num(0.25))], &mut out);
+///
+/// assert_eq!(out, [Vec3::new(0, 1, 0), Vec3::new(2, 1, 0)]);
+/// ```
+pub fn morph(base: &[Vec3], targets: &[(&[Vec3], SignedFractional)], out: &mut [Vec3]) {
+    assert_eq!(
+        base.len(),
+        out.len(),
+        "The output needs exactly one position per base vertex"
+    );
+    assert!(
+        targets.iter().all(|(target, _)| target.len() == base.len()),
+        "Every morph target needs exactly one position per base vertex"
+    );
+
+    for (vertex, (position, base_position)) in out.iter_mut().zip(base).enumerate() {
+        *position = targets
+            .iter()
+            .fold(*base_position, |blended, (target, weight)| {
+                blended + (target[vertex] - *base_position) * *weight
+            });
+    }
+}
+
+/// Transforms `position` by every bone matrix of `bone_weights` and blends the results by their weights
+///
+/// The weights get divided by their sum, so they don't have to add up to one. Without any weight `position` is
+/// returned unchanged. The bone matrices are expected to be affine, the w component of the transformed points is
+/// ignored.
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::matrix::Mat4;
+/// # use skala_engine_numerics::mesh::skin_vertex;
+/// # use skala_engine_numerics::vector::Vec3;
+/// let root = Mat4::IDENTITY;
+/// let raised = Mat4::from_translation(Vec3::new(0, 4, 0));
+///
+/// assert_eq!(skin_vertex(Vec3::X, &[(root, 1.into()), (raised, 1.into())]), Vec3::new(1, 2, 0));
+/// ```
+#[must_use]
+pub fn skin_vertex(position: Vec3, bone_weights: &[(Mat4, SignedFractional)]) -> Vec3 {
+    let total = bone_weights
+        .iter()
+        .fold(SignedFractional::ZERO, |total, (_, weight)| total + *weight);
+
+    if total == SignedFractional::ZERO {
+        return position;
+    }
+
+    let point = Vec4::new([position.x, position.y, position.z, SignedFractional::ONE]);
+    let blended = bone_weights
+        .iter()
+        .fold(Vec3::ZERO, |blended, (bone, weight)| {
+            let skinned = *bone * point;
+
+            blended + Vec3::new(skinned[0], skinned[1], skinned[2]) * *weight
+        });
+
+    blended / total
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat4;
+    use crate::mesh::{compute_tangents, morph, planar_uv, skin_vertex, tube_mesh};
+    use crate::vector::{Vec2, Vec3, Vec4};
+    use crate::SignedFractional;
+
+    #[test]
+    fn straight_tube() {
+        let path = [Vec3::new(1, 2, 0), Vec3::new(1, 2, 3), Vec3::new(1, 2, 7)];
+        let radius = SignedFractional::from_num(2);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        let (vertices, triangles) = tube_mesh(&path, radius, 6);
+
+        assert_eq!(vertices.len(), 18);
+        assert_eq!(triangles.len(), 24);
+        assert!(triangles
+            .iter()
+            .flatten()
+            .all(|index| *index < vertices.len()));
+
+        for (ring, center) in vertices.chunks(6).zip(path) {
+            for vertex in ring {
+                let offset = *vertex - center;
+
+                assert!((offset.len() - radius).abs() < epsilon);
+                assert!(offset.dot(Vec3::Z).abs() < epsilon);
+            }
+        }
+
+        // Triangles face away from the path
+        for [a, b, c] in &triangles {
+            let normal = (vertices[*b] - vertices[*a]).cross(vertices[*c] - vertices[*a]);
+            let outwards = vertices[*a] - Vec3::new(1, 2, vertices[*a].z);
+
+            assert!(normal.dot(outwards) > SignedFractional::ZERO);
+        }
+    }
+
+    #[test]
+    fn degenerate_tube() {
+        let (vertices, triangles) = tube_mesh(&[Vec3::ONE, Vec3::ONE], 1.into(), 4);
+
+        assert!(vertices.is_empty());
+        assert!(triangles.is_empty());
+    }
+
+    #[test]
+    fn flat_quad_tangents() {
+        let positions = [
+            Vec3::new(0, 0, 0),
+            Vec3::new(4, 0, 0),
+            Vec3::new(4, 4, 0),
+            Vec3::new(0, 4, 0),
+        ];
+        let uvs = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(1.into(), 0.into()),
+            Vec2::new(1.into(), 1.into()),
+            Vec2::new(0.into(), 1.into()),
+        ];
+        let quad = [[0, 1, 2], [0, 2, 3]];
+
+        assert_eq!(compute_tangents(&positions, &uvs, &quad), vec![Vec3::X; 4]);
+
+        // Mirroring the texture flips the tangents
+        let mirrored: Vec<Vec2> = uvs
+            .iter()
+            .map(|uv| Vec2::new(SignedFractional::ONE - uv.x, uv.y))
+            .collect();
+
+        assert_eq!(
+            compute_tangents(&positions, &mirrored, &quad),
+            vec![-Vec3::X; 4]
+        );
+    }
+
+    #[test]
+    fn tangents_are_perpendicular_to_normals() {
+        // A quad bent along its diagonal with the texture stretched over it
+        let positions = [
+            Vec3::new(0, 0, 0),
+            Vec3::new(4, 0, 1),
+            Vec3::new(4, 4, 0),
+            Vec3::new(0, 4, 2),
+        ];
+        let uvs = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(1.into(), 0.into()),
+            Vec2::new(1.into(), 1.into()),
+            Vec2::new(0.into(), 1.into()),
+        ];
+        let quad = [[0, 1, 2], [0, 2, 3]];
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        let tangents = compute_tangents(&positions, &uvs, &quad);
+        let first_normal = (positions[1] - positions[0]).cross(positions[2] - positions[0]);
+
+        assert!((tangents[1].len() - SignedFractional::ONE).abs() < epsilon);
+        assert!(tangents[1].dot(first_normal).abs() < epsilon);
+        assert!(tangents
+            .iter()
+            .all(|tangent| tangent.x > SignedFractional::ZERO));
+        assert_eq!(
+            compute_tangents(&positions, &[Vec2::ZERO; 4], &quad),
+            vec![Vec3::ZERO; 4]
+        );
+    }
+
+    #[test]
+    fn planar_projection() {
+        let origin = Vec3::new(-1, 2, 0);
+        let half = SignedFractional::from_num(0.5);
+
+        assert_eq!(planar_uv(origin, origin, Vec3::X, Vec3::Y), Vec2::ZERO);
+        assert_eq!(
+            planar_uv(Vec3::new(2, 6, 9), origin, Vec3::X, Vec3::Y),
+            Vec2::new(3.into(), 4.into()),
+            "Depth along the plane normal is ignored"
+        );
+        assert_eq!(
+            planar_uv(Vec3::new(2, 6, 9), origin, Vec3::X * half, Vec3::Y * half),
+            Vec2::new(SignedFractional::from_num(1.5), 2.into())
+        );
+        assert_eq!(
+            planar_uv(Vec3::new(0, 2, -3), origin, Vec3::Z, Vec3::X),
+            Vec2::new((-3).into(), 1.into())
+        );
+    }
+
+    #[test]
+    fn single_morph_target() {
+        let base = [Vec3::new(0, 0, 0), Vec3::new(4, -2, 6), Vec3::new(-1, 3, 1)];
+        let target = [Vec3::new(2, 2, 2), Vec3::new(0, 0, 0), Vec3::new(-1, 3, 1)];
+        let half = SignedFractional::from_num(0.5);
+        let mut out = [Vec3::ZERO; 3];
+
+        morph(&base, &[(&target, half)], &mut out);
+
+        for ((blended, from), to) in out.iter().zip(base).zip(target) {
+            assert_eq!(*blended, from.midpoint(to));
+        }
+
+        morph(&base, &[(&target, 0.into())], &mut out);
+        assert_eq!(out, base);
+
+        morph(&base, &[(&target, 1.into())], &mut out);
+        assert_eq!(out, target);
+
+        morph(&base, &[], &mut out);
+        assert_eq!(out, base);
+    }
+
+    #[test]
+    fn morph_targets_add_up() {
+        let base = [Vec3::new(1, 1, 1), Vec3::new(-2, 0, 4)];
+        let raise = [Vec3::new(1, 3, 1), Vec3::new(-2, 2, 4)];
+        let widen = [Vec3::new(3, 1, 1), Vec3::new(-4, 0, 4)];
+        let mut out = [Vec3::ZERO; 2];
+
+        morph(
+            &base,
+            &[
+                (&raise, 1.into()),
+                (&widen, SignedFractional::from_num(0.5)),
+            ],
+            &mut out,
+        );
+
+        assert_eq!(out, [Vec3::new(2, 3, 1), Vec3::new(-3, 2, 4)]);
+    }
+
+    #[test]
+    fn single_bone_skinning() {
+        let bone =
+            Mat4::from_translation(Vec3::new(1, -2, 3)) * Mat4::from_scale(Vec3::new(2, 2, 2));
+        let position = Vec3::new(3, 1, -1);
+        let transformed =
+            bone * Vec4::new([position.x, position.y, position.z, SignedFractional::ONE]);
+
+        assert_eq!(
+            skin_vertex(position, &[(bone, 1.into())]),
+            Vec3::new(transformed[0], transformed[1], transformed[2])
+        );
+        assert_eq!(
+            skin_vertex(position, &[(bone, 1.into())]),
+            Vec3::new(7, 0, 1)
+        );
+
+        // Only the ratio of the weights matters
+        assert_eq!(
+            skin_vertex(position, &[(bone, 3.into())]),
+            Vec3::new(7, 0, 1)
+        );
+        assert_eq!(skin_vertex(position, &[]), position);
+    }
+
+    #[test]
+    fn blended_bones() {
+        let position = Vec3::new(2, 0, 0);
+        let up = Mat4::from_translation(Vec3::new(0, 4, 0));
+        let stretch = Mat4::from_scale(Vec3::new(3, 1, 1));
+        let quarter = SignedFractional::from_num(0.25);
+
+        assert_eq!(
+            skin_vertex(
+                position,
+                &[(up, quarter), (stretch, SignedFractional::ONE - quarter)]
+            ),
+            Vec3::new(5, 1, 0)
+        );
+        assert_eq!(
+            skin_vertex(position, &[(up, 1.into()), (stretch, 0.into())]),
+            Vec3::new(2, 4, 0)
+        );
+        assert_eq!(
+            skin_vertex(
+                position,
+                &[
+                    (Mat4::IDENTITY, 1.into()),
+                    (up, 1.into()),
+                    (stretch, 2.into())
+                ]
+            ),
+            Vec3::new(4, 1, 0)
+        );
+    }
+}
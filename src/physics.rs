@@ -0,0 +1,365 @@
+use crate::matrix::Mat3;
+use crate::quat::Quat;
+use crate::vector::Vec3;
+use crate::SignedFractional;
+
+/// Returns the velocity of a point offset by `r` from the center of a rigid body moving with the velocity `linear`
+/// and spinning with the angular velocity `angular`
+///
+/// Evaluate this for both bodies at a contact point, their difference is the relative velocity used to resolve
+/// collision impulses.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::physics::point_velocity;
+/// # use skala_engine_numerics::Vec3;
+/// // A body moving along x while spinning around y
+/// let linear = Vec3::new(1, 0, 0);
+/// let angular = Vec3::new(0, 2, 0);
+///
+/// assert_eq!(point_velocity(linear, angular, Vec3::ZERO), linear);
+/// assert_eq!(point_velocity(linear, angular, Vec3::Z), Vec3::new(3, 0, 0));
+/// ```
+#[must_use]
+pub fn point_velocity(linear: Vec3, angular: Vec3, r: Vec3) -> Vec3 {
+    linear + angular.cross(r)
+}
+
+/// Returns the contact normal and penetration depth of the spheres of radius `r1` around `c1` and `r2` around `c2`,
+/// or `None` if they don't touch
+///
+/// The normal is of unit length and points from the first sphere towards the second, moving the second sphere by
+/// the normal times the depth separates them. Touching spheres report a depth of zero. Spheres with coincident
+/// centers have no preferred direction and get pushed apart along the x axis.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::physics::sphere_sphere_contact;
+/// # use skala_engine_numerics::Vec3;
+/// let contact = sphere_sphere_contact(Vec3::ZERO, 2.into(), Vec3::new(0, 3, 0), 2.into());
+///
+/// assert_eq!(contact, Some((Vec3::Y, 1.into())));
+/// assert_eq!(sphere_sphere_contact(Vec3::ZERO, 2.into(), Vec3::new(0, 5, 0), 2.into()), None);
+/// ```
+#[must_use]
+pub fn sphere_sphere_contact(
+    c1: Vec3,
+    r1: SignedFractional,
+    c2: Vec3,
+    r2: SignedFractional,
+) -> Option<(Vec3, SignedFractional)> {
+    let offset = c2 - c1;
+    let distance = offset.len();
+    let depth = r1 + r2 - distance;
+
+    if depth < SignedFractional::ZERO {
+        return None;
+    }
+
+    if distance == SignedFractional::ZERO {
+        return Some((Vec3::X, depth));
+    }
+
+    Some((offset / distance, depth))
+}
+
+/// Returns the inertia `tensor` of a rigid body expressed in its local frame rotated into the frame of `rotation`
+///
+/// Computes `rotation * tensor * rotation.transpose()`, which keeps the local tensor constant while the body turns.
+/// `rotation` has to be a pure rotation, so its transpose is its inverse.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::matrix::Mat3;
+/// # use skala_engine_numerics::physics::rotate_inertia;
+/// # use skala_engine_numerics::Vec3;
+/// let tensor = Mat3::from_diagonal(Vec3::new(1, 2, 3));
+/// // A quarter turn around z
+/// let rotation = Mat3::from_cols(Vec3::Y, -Vec3::X, Vec3::Z);
+///
+/// assert_eq!(rotate_inertia(tensor, rotation), Mat3::from_diagonal(Vec3::new(2, 1, 3)));
+/// ```
+#[must_use]
+pub fn rotate_inertia(tensor: Mat3, rotation: Mat3) -> Mat3 {
+    rotation * tensor * rotation.transpose()
+}
+
+/// Advances `orientation` by spinning it with the world space `angular_velocity` for `dt` seconds
+///
+/// Takes a single explicit Euler step of the quaternion derivative `ω * q / 2` and normalizes the result, which keeps
+/// the orientation a unit quaternion while it gets integrated every frame.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::physics::integrate_orientation;
+/// # use skala_engine_numerics::quat::Quat;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let spinning = integrate_orientation(Quat::IDENTITY, Vec3::new(0, 0, 1), SignedFractional::from_num(0.01));
+///
+/// assert!(spinning.rotate_vec3(Vec3::X).y > 0);
+/// assert_eq!(integrate_orientation(Quat::IDENTITY, Vec3::ZERO, 1.into()), Quat::IDENTITY);
+/// ```
+#[must_use]
+pub fn integrate_orientation(
+    orientation: Quat,
+    angular_velocity: Vec3,
+    dt: SignedFractional,
+) -> Quat {
+    let spin = Quat::new(
+        angular_velocity.x,
+        angular_velocity.y,
+        angular_velocity.z,
+        SignedFractional::ZERO,
+    );
+    let mut advanced = orientation + spin * orientation * (dt / 2);
+
+    advanced.normalize();
+    advanced
+}
+
+/// Applies `impulse` at the offset `r` from the center of mass of a rigid body, changing its `linear` and `angular`
+/// velocities
+///
+/// The linear velocity changes by the impulse times `inv_mass`, the angular one by the torque impulse `r × impulse`
+/// transformed by the world space `inv_inertia` tensor. Impulses through the center of mass don't change the spin.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::matrix::Mat3;
+/// # use skala_engine_numerics::physics::apply_impulse;
+/// # use skala_engine_numerics::Vec3;
+/// let mut linear = Vec3::ZERO;
+/// let mut angular = Vec3::ZERO;
+///
+/// // Pushing along y at the positive x side of the body
+/// apply_impulse(&mut linear, &mut angular, 2.into(), Mat3::IDENTITY, Vec3::Y, Vec3::X);
+///
+/// assert_eq!(linear, Vec3::new(0, 2, 0));
+/// assert_eq!(angular, Vec3::Z);
+/// ```
+pub fn apply_impulse(
+    linear: &mut Vec3,
+    angular: &mut Vec3,
+    inv_mass: SignedFractional,
+    inv_inertia: Mat3,
+    impulse: Vec3,
+    r: Vec3,
+) {
+    *linear += impulse * inv_mass;
+    *angular += inv_inertia * r.cross(impulse);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::Mat3;
+    use crate::physics::{
+        apply_impulse, integrate_orientation, point_velocity, rotate_inertia, sphere_sphere_contact,
+    };
+    use crate::quat::Quat;
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn pure_rotation() {
+        // Spinning counter clockwise around z at 3 radians per second
+        let angular = Vec3::new(0, 0, 3);
+        let r = Vec3::new(2, 0, 0);
+
+        let velocity = point_velocity(Vec3::ZERO, angular, r);
+
+        // Tangential speed is the angular speed times the distance from the axis
+        assert_eq!(velocity, Vec3::new(0, 6, 0));
+        assert_eq!(velocity.dot(r), 0);
+        assert_eq!(point_velocity(Vec3::ZERO, angular, -r), -velocity);
+
+        // Points on the rotation axis don't move
+        assert_eq!(point_velocity(Vec3::ZERO, angular, Vec3::Z), Vec3::ZERO);
+    }
+
+    #[test]
+    fn relative_velocity() {
+        let linear = Vec3::new(1, -2, 0);
+        let angular = Vec3::new(1, 0, 0);
+        let r = Vec3::new(0, 0, 2);
+
+        assert_eq!(point_velocity(linear, angular, r), Vec3::new(1, -4, 0));
+        assert_eq!(point_velocity(linear, Vec3::ZERO, r), linear);
+    }
+
+    #[test]
+    fn overlapping_spheres() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+
+        let (normal, depth) =
+            sphere_sphere_contact(Vec3::new(1, 1, 1), 3.into(), Vec3::new(4, 5, 1), 4.into())
+                .unwrap();
+        assert_eq!(
+            normal,
+            Vec3::new(
+                SignedFractional::from_num(0.6),
+                SignedFractional::from_num(0.8),
+                0
+            )
+        );
+        assert_eq!(depth, 2);
+
+        // Swapping the spheres flips the normal but keeps the depth
+        let (flipped, depth) =
+            sphere_sphere_contact(Vec3::new(4, 5, 1), 4.into(), Vec3::new(1, 1, 1), 3.into())
+                .unwrap();
+        assert_eq!(flipped, -normal);
+        assert_eq!(depth, 2);
+
+        // One sphere inside of the other
+        let (normal, depth) =
+            sphere_sphere_contact(Vec3::ZERO, 5.into(), Vec3::new(0, 0, -1), 1.into()).unwrap();
+        assert_eq!(normal, -Vec3::Z);
+        assert_eq!(depth, 5);
+
+        // Separating along the normal by the depth leaves the spheres touching
+        let c1 = Vec3::new(-2, 3, 1);
+        let c2 = Vec3::new(1, 2, 2);
+        let (normal, depth) = sphere_sphere_contact(c1, 2.into(), c2, 2.into()).unwrap();
+        let (_, remaining) =
+            sphere_sphere_contact(c1, 2.into(), c2 + normal * depth, 2.into()).unwrap();
+        assert!(remaining.abs() < epsilon);
+    }
+
+    #[test]
+    fn touching_and_separated_spheres() {
+        assert_eq!(
+            sphere_sphere_contact(Vec3::ZERO, 2.into(), Vec3::new(5, 0, 0), 3.into()),
+            Some((Vec3::X, 0.into()))
+        );
+        assert_eq!(
+            sphere_sphere_contact(Vec3::ZERO, 2.into(), Vec3::new(6, 0, 0), 3.into()),
+            None
+        );
+        assert_eq!(
+            sphere_sphere_contact(Vec3::new(1, 2, 3), 1.into(), Vec3::new(-9, 2, 3), 1.into()),
+            None
+        );
+    }
+
+    #[test]
+    fn coincident_centers() {
+        let center = Vec3::new(3, -1, 2);
+
+        let (normal, depth) = sphere_sphere_contact(center, 2.into(), center, 1.into()).unwrap();
+
+        assert_eq!(normal.len(), 1);
+        assert_eq!(depth, 3);
+    }
+
+    #[test]
+    fn inertia_rotation() {
+        let tensor = Mat3::from_cols(Vec3::new(4, 1, 0), Vec3::new(1, 6, 2), Vec3::new(0, 2, 9));
+
+        assert_eq!(rotate_inertia(tensor, Mat3::IDENTITY), tensor);
+
+        // A quarter turn around z swaps the x and y moments and negates the products mixing x with the others
+        let quarter_turn = Mat3::from_cols(Vec3::Y, -Vec3::X, Vec3::Z);
+        let rotated = rotate_inertia(tensor, quarter_turn);
+
+        assert_eq!(rotated.x_axis, Vec3::new(6, -1, -2));
+        assert_eq!(rotated.y_axis, Vec3::new(-1, 4, 0));
+        assert_eq!(rotated.z_axis, Vec3::new(-2, 0, 9));
+        assert_eq!(rotated, rotated.transpose());
+        assert_eq!(rotate_inertia(rotated, quarter_turn.transpose()), tensor);
+    }
+
+    #[test]
+    fn constant_spin() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let angular_velocity = Vec3::new(0, 0, SignedFractional::from_num(1.5));
+        let dt = SignedFractional::ONE / SignedFractional::from_num(60);
+        let mut orientation = Quat::IDENTITY;
+
+        // Three seconds at 60 steps per second
+        for step in 1..=180 {
+            orientation = integrate_orientation(orientation, angular_velocity, dt);
+
+            if step % 60 == 0 {
+                let elapsed = SignedFractional::from_num(step / 60);
+                let expected = Quat::from_axis_angle(Vec3::Z, angular_velocity.z * elapsed);
+
+                assert!(orientation.approx_eq(expected, epsilon));
+            }
+        }
+
+        assert!((orientation.dot(orientation) - SignedFractional::ONE).abs() < epsilon);
+    }
+
+    #[test]
+    fn central_impulse() {
+        let inv_inertia = Mat3::from_diagonal(Vec3::new(1, 2, 3));
+        let mut linear = Vec3::new(1, 0, 0);
+        let mut angular = Vec3::new(0, 1, 0);
+
+        apply_impulse(
+            &mut linear,
+            &mut angular,
+            SignedFractional::from_num(0.5),
+            inv_inertia,
+            Vec3::new(2, 4, 0),
+            Vec3::ZERO,
+        );
+
+        assert_eq!(linear, Vec3::new(2, 2, 0));
+        assert_eq!(angular, Vec3::new(0, 1, 0));
+
+        // Pushing along the line through the center of mass doesn't induce spin either
+        apply_impulse(
+            &mut linear,
+            &mut angular,
+            SignedFractional::from_num(0.5),
+            inv_inertia,
+            Vec3::new(0, 0, -2),
+            Vec3::new(0, 0, 3),
+        );
+
+        assert_eq!(linear, Vec3::new(2, 2, -1));
+        assert_eq!(angular, Vec3::new(0, 1, 0));
+    }
+
+    #[test]
+    fn off_center_impulse() {
+        let inv_inertia = Mat3::from_diagonal(Vec3::new(1, 2, SignedFractional::from_num(0.5)));
+        let mut linear = Vec3::ZERO;
+        let mut angular = Vec3::ZERO;
+        let r = Vec3::new(2, 0, 0);
+
+        apply_impulse(
+            &mut linear,
+            &mut angular,
+            1.into(),
+            inv_inertia,
+            Vec3::new(0, 3, 0),
+            r,
+        );
+
+        assert_eq!(linear, Vec3::new(0, 3, 0));
+        assert_eq!(angular, Vec3::new(0, 0, 3));
+
+        // The point that got pushed now moves faster along the impulse than the center
+        assert_eq!(point_velocity(linear, angular, r), Vec3::new(0, 9, 0));
+
+        // The opposite impulse at the same point cancels both
+        apply_impulse(
+            &mut linear,
+            &mut angular,
+            1.into(),
+            inv_inertia,
+            Vec3::new(0, -3, 0),
+            r,
+        );
+
+        assert_eq!(linear, Vec3::ZERO);
+        assert_eq!(angular, Vec3::ZERO);
+    }
+}
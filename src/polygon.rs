@@ -0,0 +1,324 @@
+use crate::vector::Vec2;
+use crate::SignedFractional;
+
+/// Calculates the signed area of a polygon
+///
+/// The area is positive for polygons wound counterclockwise and negative for polygons wound clockwise.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polygon::signed_area;
+/// # use skala_engine_numerics::Vec2;
+/// let square = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(2.into(), 2.into()),
+///     Vec2::new(0.into(), 2.into()),
+/// ];
+///
+/// assert_eq!(signed_area(&square), 4);
+/// ```
+#[must_use]
+pub fn signed_area(polygon: &[Vec2]) -> SignedFractional {
+    let doubled = (0..polygon.len())
+        .map(|i| polygon[i].cross(polygon[(i + 1) % polygon.len()]))
+        .fold(SignedFractional::ZERO, |total, cross| total + cross);
+
+    doubled / SignedFractional::from_num(2)
+}
+
+/// Moves every edge of a polygon `distance` units along its outward normal
+///
+/// Positive distances grow the polygon and negative distances shrink it. The polygon may be wound either way.
+/// The new vertices are the intersections of neighbouring offset edges, so convex corners stay sharp (mitered).
+///
+/// No attempt is made to clean up the result. Very sharp convex corners grow long spikes and around sharp concave
+/// corners (or whenever `distance` exceeds the local thickness of the polygon) the offset edges cross each other and
+/// the resulting polygon is self intersecting.
+///
+/// Polygons with fewer than three points are returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polygon::offset_polygon;
+/// # use skala_engine_numerics::Vec2;
+/// let square = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(2.into(), 2.into()),
+///     Vec2::new(0.into(), 2.into()),
+/// ];
+///
+/// let grown = offset_polygon(&square, 1.into());
+///
+/// assert_eq!(grown[0], Vec2::new((-1).into(), (-1).into()));
+/// ```
+#[must_use]
+pub fn offset_polygon(polygon: &[Vec2], distance: SignedFractional) -> Vec<Vec2> {
+    let count = polygon.len();
+
+    if count < 3 {
+        return polygon.to_vec();
+    }
+
+    let orientation = if signed_area(polygon) < SignedFractional::ZERO {
+        -SignedFractional::ONE
+    } else {
+        SignedFractional::ONE
+    };
+
+    let edges: Vec<(Vec2, Vec2)> = (0..count)
+        .map(|i| {
+            let start = polygon[i];
+            let direction = polygon[(i + 1) % count] - start;
+            let normal = Vec2::new(direction.y, -direction.x)
+                .try_get_normalized()
+                .unwrap_or(Vec2::ZERO);
+
+            (start + normal * (distance * orientation), direction)
+        })
+        .collect();
+
+    (0..count)
+        .map(|i| {
+            let (previous_start, previous_direction) = edges[(i + count - 1) % count];
+            let (start, direction) = edges[i];
+            let denominator = previous_direction.cross(direction);
+
+            if denominator == SignedFractional::ZERO {
+                return start;
+            }
+
+            let t = (start - previous_start).cross(direction) / denominator;
+
+            previous_start + previous_direction * t
+        })
+        .collect()
+}
+
+/// Checks whether `point` lies inside the triangle `a`, `b`, `c` or on its boundary
+///
+/// The triangle may be wound either way.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polygon::point_in_triangle;
+/// # use skala_engine_numerics::Vec2;
+/// let a = Vec2::new(0.into(), 0.into());
+/// let b = Vec2::new(4.into(), 0.into());
+/// let c = Vec2::new(0.into(), 4.into());
+///
+/// assert!(point_in_triangle(Vec2::new(1.into(), 1.into()), a, b, c));
+/// assert!(!point_in_triangle(Vec2::new(3.into(), 3.into()), a, b, c));
+/// ```
+#[must_use]
+pub fn point_in_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    let ab = (b - a).cross(point - a);
+    let bc = (c - b).cross(point - b);
+    let ca = (a - c).cross(point - c);
+
+    let has_negative =
+        ab < SignedFractional::ZERO || bc < SignedFractional::ZERO || ca < SignedFractional::ZERO;
+    let has_positive =
+        ab > SignedFractional::ZERO || bc > SignedFractional::ZERO || ca > SignedFractional::ZERO;
+
+    !(has_negative && has_positive)
+}
+
+/// Splits a simple polygon into triangles using ear clipping
+///
+/// Returns triples of indices into `polygon`, every triangle is wound the same way as the polygon.
+/// The polygon may be convex or concave but must not intersect itself. If the polygon is degenerate (for example
+/// when all of its points are collinear) the triangles found before getting stuck are returned.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polygon::triangulate;
+/// # use skala_engine_numerics::Vec2;
+/// let square = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(2.into(), 2.into()),
+///     Vec2::new(0.into(), 2.into()),
+/// ];
+///
+/// assert_eq!(triangulate(&square).len(), 2);
+/// ```
+#[must_use]
+pub fn triangulate(polygon: &[Vec2]) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+
+    if polygon.len() < 3 {
+        return triangles;
+    }
+
+    let orientation = if signed_area(polygon) < SignedFractional::ZERO {
+        -SignedFractional::ONE
+    } else {
+        SignedFractional::ONE
+    };
+
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+
+    while remaining.len() > 3 {
+        let count = remaining.len();
+
+        let ear = (0..count).find(|&i| {
+            let previous = remaining[(i + count - 1) % count];
+            let current = remaining[i];
+            let next = remaining[(i + 1) % count];
+
+            is_ear(polygon, &remaining, [previous, current, next], orientation)
+        });
+
+        let Some(ear) = ear else {
+            return triangles;
+        };
+
+        triangles.push([
+            remaining[(ear + count - 1) % count],
+            remaining[ear],
+            remaining[(ear + 1) % count],
+        ]);
+        remaining.remove(ear);
+    }
+
+    triangles.push([remaining[0], remaining[1], remaining[2]]);
+
+    triangles
+}
+
+fn is_ear(
+    polygon: &[Vec2],
+    remaining: &[usize],
+    corner: [usize; 3],
+    orientation: SignedFractional,
+) -> bool {
+    let [a, b, c] = corner.map(|index| polygon[index]);
+
+    if (b - a).cross(c - b) * orientation <= SignedFractional::ZERO {
+        return false;
+    }
+
+    !remaining
+        .iter()
+        .filter(|index| !corner.contains(index))
+        .any(|&index| point_in_triangle(polygon[index], a, b, c))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::polygon::{offset_polygon, point_in_triangle, signed_area, triangulate};
+    use crate::vector::Vec2;
+    use crate::SignedFractional;
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(4.into(), 0.into()),
+            Vec2::new(4.into(), 4.into()),
+            Vec2::new(0.into(), 4.into()),
+        ]
+    }
+
+    #[test]
+    fn area() {
+        let mut square = square();
+
+        assert_eq!(signed_area(&square), 16);
+
+        square.reverse();
+        assert_eq!(signed_area(&square), -16);
+    }
+
+    #[test]
+    fn inset_square() {
+        let inset = offset_polygon(&square(), (-1).into());
+
+        assert_eq!(
+            inset,
+            vec![
+                Vec2::new(1.into(), 1.into()),
+                Vec2::new(3.into(), 1.into()),
+                Vec2::new(3.into(), 3.into()),
+                Vec2::new(1.into(), 3.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn inset_clockwise_square() {
+        let mut square = square();
+        square.reverse();
+
+        let inset = offset_polygon(&square, (-1).into());
+
+        assert_eq!(
+            inset,
+            vec![
+                Vec2::new(1.into(), 3.into()),
+                Vec2::new(3.into(), 3.into()),
+                Vec2::new(3.into(), 1.into()),
+                Vec2::new(1.into(), 1.into()),
+            ]
+        );
+    }
+
+    fn triangle_area(polygon: &[Vec2], triangle: [usize; 3]) -> SignedFractional {
+        signed_area(&triangle.map(|index| polygon[index]))
+    }
+
+    #[test]
+    fn triangulate_convex_quad() {
+        let quad = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(5.into(), 1.into()),
+            Vec2::new(4.into(), 4.into()),
+            Vec2::new(1.into(), 3.into()),
+        ];
+
+        let triangles = triangulate(&quad);
+
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(
+            triangles
+                .iter()
+                .map(|triangle| triangle_area(&quad, *triangle))
+                .sum::<SignedFractional>(),
+            signed_area(&quad)
+        );
+    }
+
+    #[test]
+    fn triangulate_concave_polygon() {
+        let notched = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(4.into(), 0.into()),
+            Vec2::new(4.into(), 4.into()),
+            Vec2::new(2.into(), 2.into()),
+            Vec2::new(0.into(), 4.into()),
+        ];
+        let inside_notch = Vec2::new(2.into(), 3.into());
+
+        let triangles = triangulate(&notched);
+
+        assert_eq!(triangles.len(), 3);
+        for triangle in &triangles {
+            let [a, b, c] = triangle.map(|index| notched[index]);
+
+            assert!(triangle_area(&notched, *triangle) > SignedFractional::ZERO);
+            assert!(!point_in_triangle(inside_notch, a, b, c));
+        }
+        assert_eq!(
+            triangles
+                .iter()
+                .map(|triangle| triangle_area(&notched, *triangle))
+                .sum::<SignedFractional>(),
+            12
+        );
+    }
+}
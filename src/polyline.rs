@@ -0,0 +1,539 @@
+use crate::vector::{Vec2, Vec3, Vector};
+use crate::SignedFractional;
+
+/// Rounds the corners of a polyline or polygon using Chaikin's corner cutting algorithm
+///
+/// Every iteration replaces each edge with two points placed at one and three quarters of its length.
+/// An open polyline keeps its first and last point so the smoothed path still starts and ends where the original did,
+/// a closed polygon also cuts the edge joining the last point back to the first.
+///
+/// Polylines with fewer than two points are returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::chaikin_smooth;
+/// # use skala_engine_numerics::Vec2;
+/// let line = [Vec2::new(0.into(), 0.into()), Vec2::new(4.into(), 0.into())];
+///
+/// let smoothed = chaikin_smooth(&line, 1, false);
+///
+/// assert_eq!(smoothed.len(), 4);
+/// assert_eq!(smoothed[1], Vec2::new(1.into(), 0.into()));
+/// ```
+#[must_use]
+pub fn chaikin_smooth(points: &[Vec2], iterations: u32, closed: bool) -> Vec<Vec2> {
+    let mut current = points.to_vec();
+
+    if current.len() < 2 {
+        return current;
+    }
+
+    let quarter = SignedFractional::from_num(0.25);
+    let three_quarters = SignedFractional::from_num(0.75);
+
+    for _ in 0..iterations {
+        let mut next = Vec::with_capacity(current.len() * 2 + 2);
+
+        if !closed {
+            next.push(current[0]);
+        }
+
+        let edge_count = if closed {
+            current.len()
+        } else {
+            current.len() - 1
+        };
+
+        for i in 0..edge_count {
+            let start = current[i];
+            let end = current[(i + 1) % current.len()];
+
+            next.push(start * three_quarters + end * quarter);
+            next.push(start * quarter + end * three_quarters);
+        }
+
+        if !closed {
+            next.push(current[current.len() - 1]);
+        }
+
+        current = next;
+    }
+
+    current
+}
+
+/// Returns the total length of a polyline
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::polyline_length;
+/// # use skala_engine_numerics::Vec2;
+/// let path = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(3.into(), 4.into()),
+///     Vec2::new(3.into(), 10.into()),
+/// ];
+///
+/// assert_eq!(polyline_length(&path), 11);
+/// ```
+#[must_use]
+pub fn polyline_length(points: &[Vec2]) -> SignedFractional {
+    points
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).len())
+        .fold(SignedFractional::ZERO, |total, len| total + len)
+}
+
+/// Returns the point lying `distance` units along a polyline measured from its first point
+///
+/// Distances past either end of the polyline are clamped to its endpoints.
+/// Returns `None` if the polyline has no points.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::sample_polyline;
+/// # use skala_engine_numerics::Vec2;
+/// let path = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(4.into(), 0.into()),
+///     Vec2::new(4.into(), 4.into()),
+/// ];
+///
+/// assert_eq!(sample_polyline(&path, 6.into()), Some(Vec2::new(4.into(), 2.into())));
+/// assert_eq!(sample_polyline(&path, 100.into()), Some(Vec2::new(4.into(), 4.into())));
+/// ```
+#[must_use]
+pub fn sample_polyline(points: &[Vec2], distance: SignedFractional) -> Option<Vec2> {
+    let mut remaining = distance.max(SignedFractional::ZERO);
+
+    for pair in points.windows(2) {
+        let segment = pair[1] - pair[0];
+        let len = segment.len();
+
+        if len != SignedFractional::ZERO && remaining <= len {
+            return Some(pair[0] + segment * (remaining / len));
+        }
+
+        remaining -= len;
+    }
+
+    points.last().copied()
+}
+
+/// Resamples a polyline into points spaced `spacing` units apart along its length
+///
+/// The first point of the result is the first point of the polyline.
+/// If the polyline length isn't a multiple of `spacing` the remainder past the last sample is dropped.
+///
+/// # Panics
+/// When `spacing` isn't positive
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::resample_uniform;
+/// # use skala_engine_numerics::Vec2;
+/// let path = [Vec2::new(0.into(), 0.into()), Vec2::new(0.into(), 9.into())];
+///
+/// let samples = resample_uniform(&path, 3.into());
+///
+/// assert_eq!(samples.len(), 4);
+/// assert_eq!(samples[3], Vec2::new(0.into(), 9.into()));
+/// ```
+#[must_use]
+pub fn resample_uniform(points: &[Vec2], spacing: SignedFractional) -> Vec<Vec2> {
+    assert!(
+        spacing > SignedFractional::ZERO,
+        "Resampling spacing has to be positive"
+    );
+
+    let total = polyline_length(points);
+    let count = (total / spacing).floor().to_num::<usize>();
+
+    (0..=count)
+        .filter_map(|i| sample_polyline(points, spacing * SignedFractional::from_num(i)))
+        .collect()
+}
+
+/// Returns a unit tangent for every point of a polyline
+///
+/// Interior points get the average direction of the edges meeting at them, the endpoints get the direction of
+/// their only edge. Zero length edges created by duplicate consecutive points are skipped in favour of the nearest
+/// proper edge. Where the path doubles back on itself the outgoing edge direction is used and if every point of
+/// the polyline is the same the tangents are zero vectors.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::polyline_tangents;
+/// # use skala_engine_numerics::Vec2;
+/// let path = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(5.into(), 0.into()),
+///     Vec2::new(5.into(), 0.into()),
+///     Vec2::new(9.into(), 0.into()),
+/// ];
+///
+/// assert!(polyline_tangents(&path).iter().all(|tangent| *tangent == Vec2::X));
+/// ```
+#[must_use]
+pub fn polyline_tangents(points: &[Vec2]) -> Vec<Vec2> {
+    let points: Vec<Vector<2>> = points.iter().copied().map(Vector::from).collect();
+
+    unit_tangents(&points).into_iter().map(Vec2::from).collect()
+}
+
+/// Returns the signed angle in radians the path turns by at every interior point of a polyline
+///
+/// Positive angles are counterclockwise turns and negative angles clockwise turns, a straight continuation is zero.
+/// The result has two fewer elements than `points` and is empty for polylines with fewer than three points.
+/// Turns at a duplicated point are reported as zero.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::turn_angles;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let path = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(2.into(), 2.into()),
+/// ];
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// let angles = turn_angles(&path);
+///
+/// assert_eq!(angles.len(), 1);
+/// assert!((angles[0] - SignedFractional::FRAC_PI_2).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn turn_angles(points: &[Vec2]) -> Vec<SignedFractional> {
+    points
+        .windows(3)
+        .map(|triple| {
+            let incoming = triple[1] - triple[0];
+            let outgoing = triple[2] - triple[1];
+
+            incoming.angle_to(outgoing)
+        })
+        .collect()
+}
+
+/// Returns the total curvature of a polyline, the sum of the absolute angles it turns by
+///
+/// Useful as a cost when comparing how smooth paths are, a straight path has zero curvature.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::total_curvature;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let zigzag = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(1.into(), 1.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(3.into(), 1.into()),
+/// ];
+/// let epsilon = SignedFractional::from_num(0.000_01);
+///
+/// assert!((total_curvature(&zigzag) - SignedFractional::PI).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn total_curvature(points: &[Vec2]) -> SignedFractional {
+    turn_angles(points)
+        .into_iter()
+        .fold(SignedFractional::ZERO, |total, angle| total + angle.abs())
+}
+
+/// Returns rotation minimizing `(right, up, forward)` frames at every point of a 3d polyline
+///
+/// `forward` is the unit tangent of the path, computed like [`polyline_tangents`] does in 2d. `up` is carried from
+/// point to point with as little rotation around `forward` as possible so ribbons and tubes extruded along the path
+/// don't twist, `right` completes the right handed orthonormal frame. The first `up` is an arbitrary direction
+/// perpendicular to the first tangent. Returns no frames when every point of the polyline is the same.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::polyline::parallel_transport_frames;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let path = [Vec3::new(0, 0, 0), Vec3::new(0, 0, 4), Vec3::new(0, 0, 8)];
+///
+/// let frames = parallel_transport_frames(&path);
+///
+/// assert_eq!(frames.len(), 3);
+/// assert!(frames.iter().all(|frame| *frame == frames[0]));
+/// assert_eq!(frames[0].2, Vec3::Z);
+/// ```
+#[must_use]
+pub fn parallel_transport_frames(points: &[Vec3]) -> Vec<(Vec3, Vec3, Vec3)> {
+    let path: Vec<Vector<3>> = points.iter().copied().map(Vector::from).collect();
+    let tangents: Vec<Vec3> = unit_tangents(&path).into_iter().map(Vec3::from).collect();
+
+    let Some(first_tangent) = tangents.first().filter(|tangent| **tangent != Vec3::ZERO) else {
+        return Vec::new();
+    };
+
+    let (_, mut up) = first_tangent.any_orthonormal_basis();
+    let mut frames = Vec::with_capacity(points.len());
+    frames.push((up.cross(*first_tangent), up, *first_tangent));
+
+    // Wang et al.'s double reflection method, reflecting the frame across the plane between two consecutive points
+    // and then across the plane between the reflected and the actual next tangent
+    for (i, pair) in points.windows(2).enumerate() {
+        let (tangent, next_tangent) = (tangents[i], tangents[i + 1]);
+
+        let reflect = |v: Vec3, normal: Vec3| {
+            let normal_len_pow2 = normal.len_pow2();
+
+            if normal_len_pow2 == SignedFractional::ZERO {
+                v
+            } else {
+                v - normal * (SignedFractional::from_num(2) * normal.dot(v) / normal_len_pow2)
+            }
+        };
+
+        let step = pair[1] - pair[0];
+        let reflected_up = reflect(up, step);
+        let reflected_tangent = reflect(tangent, step);
+
+        up = reflect(reflected_up, next_tangent - reflected_tangent)
+            .try_get_normalized()
+            .unwrap_or(up);
+
+        frames.push((up.cross(next_tangent), up, next_tangent));
+    }
+
+    frames
+}
+
+/// Returns a unit tangent for every point of a polyline of any dimension, see [`polyline_tangents`]
+fn unit_tangents<const N: usize>(points: &[Vector<N>]) -> Vec<Vector<N>> {
+    let directions: Vec<Option<Vector<N>>> = points
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).try_get_normalized())
+        .collect();
+
+    (0..points.len())
+        .map(|i| {
+            let incoming = directions[..i]
+                .iter()
+                .rev()
+                .find_map(|direction| *direction);
+            let outgoing = directions[i..].iter().find_map(|direction| *direction);
+
+            match (incoming, outgoing) {
+                (Some(incoming), Some(outgoing)) => (incoming + outgoing)
+                    .try_get_normalized()
+                    .unwrap_or(outgoing),
+                (Some(direction), None) | (None, Some(direction)) => direction,
+                (None, None) => Vector::ZERO,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::polyline::{
+        chaikin_smooth, parallel_transport_frames, polyline_length, polyline_tangents,
+        resample_uniform, total_curvature, turn_angles,
+    };
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn chaikin_closed_square() {
+        let square = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(4.into(), 0.into()),
+            Vec2::new(4.into(), 4.into()),
+            Vec2::new(0.into(), 4.into()),
+        ];
+
+        let smoothed = chaikin_smooth(&square, 1, true);
+
+        assert_eq!(
+            smoothed,
+            vec![
+                Vec2::new(1.into(), 0.into()),
+                Vec2::new(3.into(), 0.into()),
+                Vec2::new(4.into(), 1.into()),
+                Vec2::new(4.into(), 3.into()),
+                Vec2::new(3.into(), 4.into()),
+                Vec2::new(1.into(), 4.into()),
+                Vec2::new(0.into(), 3.into()),
+                Vec2::new(0.into(), 1.into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn chaikin_open_keeps_endpoints() {
+        let line = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(4.into(), 0.into()),
+            Vec2::new(4.into(), 4.into()),
+        ];
+
+        let smoothed = chaikin_smooth(&line, 2, false);
+
+        assert_eq!(smoothed.first(), line.first());
+        assert_eq!(smoothed.last(), line.last());
+        assert_eq!(smoothed.len(), 12);
+    }
+
+    #[test]
+    fn length() {
+        let path = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(0.into(), 5.into()),
+            Vec2::new(3.into(), 9.into()),
+        ];
+
+        assert_eq!(polyline_length(&path), 10);
+        assert_eq!(polyline_length(&path[..1]), 0);
+    }
+
+    #[test]
+    fn resample_straight_segment() {
+        let segment = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(10.into(), 0.into()),
+        ];
+
+        let samples = resample_uniform(&segment, 2.into());
+
+        assert_eq!(samples.len(), 6);
+        assert_eq!(samples[0], segment[0]);
+        assert_eq!(samples[5], segment[1]);
+    }
+
+    #[test]
+    fn resample_empty() {
+        assert!(resample_uniform(&[], 2.into()).is_empty());
+    }
+
+    #[test]
+    fn tangents_straight_line() {
+        let line = [
+            Vec2::new(0.into(), 2.into()),
+            Vec2::new(0.into(), 4.into()),
+            Vec2::new(0.into(), 4.into()),
+            Vec2::new(0.into(), 9.into()),
+        ];
+
+        assert_eq!(polyline_tangents(&line), vec![Vec2::Y; 4]);
+    }
+
+    #[test]
+    fn tangents_corner_bisector() {
+        let corner = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(2.into(), 0.into()),
+            Vec2::new(2.into(), 2.into()),
+        ];
+        let epsilon = SignedFractional::from_num(0.0001);
+
+        let tangents = polyline_tangents(&corner);
+
+        assert_eq!(tangents[0], Vec2::X);
+        assert_eq!(tangents[2], Vec2::Y);
+        assert_eq!(tangents[1].x, tangents[1].y);
+        assert!(tangents[1].x > SignedFractional::ZERO);
+        assert!((tangents[1].len_pow2() - SignedFractional::ONE).abs() < epsilon);
+    }
+
+    #[test]
+    fn turn_angle_signs() {
+        let epsilon = SignedFractional::from_num(0.000_001);
+        let path = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(1.into(), 0.into()),
+            Vec2::new(1.into(), 1.into()),
+            Vec2::new(2.into(), 1.into()),
+            Vec2::new(3.into(), 1.into()),
+        ];
+
+        let angles = turn_angles(&path);
+
+        assert_eq!(angles.len(), 3);
+        assert!((angles[0] - SignedFractional::FRAC_PI_2).abs() < epsilon);
+        assert!((angles[1] + SignedFractional::FRAC_PI_2).abs() < epsilon);
+        assert!(angles[2].abs() < epsilon);
+        assert!(turn_angles(&path[..2]).is_empty());
+    }
+
+    #[test]
+    fn curvature_of_straight_line() {
+        let epsilon = SignedFractional::from_num(0.000_001);
+        let line = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(1.into(), 2.into()),
+            Vec2::new(2.into(), 4.into()),
+            Vec2::new(5.into(), 10.into()),
+        ];
+
+        assert!(total_curvature(&line) < epsilon);
+    }
+
+    #[test]
+    fn curvature_of_square() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        // Going around the square and continuing along the first edge turns at all four corners
+        let square = [
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(3.into(), 0.into()),
+            Vec2::new(3.into(), 3.into()),
+            Vec2::new(0.into(), 3.into()),
+            Vec2::new(0.into(), 0.into()),
+            Vec2::new(3.into(), 0.into()),
+        ];
+
+        assert!((total_curvature(&square) - SignedFractional::TAU).abs() < epsilon);
+    }
+
+    #[test]
+    fn planar_frames_dont_twist() {
+        // A curve in the xy plane turning both ways
+        let path = [
+            Vec3::new(0, 0, 0),
+            Vec3::new(4, 0, 0),
+            Vec3::new(7, 2, 0),
+            Vec3::new(8, 5, 0),
+            Vec3::new(7, 8, 0),
+            Vec3::new(8, 11, 0),
+            Vec3::new(12, 12, 0),
+        ];
+        let epsilon = SignedFractional::from_num(0.001);
+
+        let frames = parallel_transport_frames(&path);
+        let out_of_plane = frames[0].1.dot(Vec3::Z);
+
+        assert_eq!(frames.len(), path.len());
+
+        for (right, up, forward) in frames {
+            assert!((right.len() - SignedFractional::ONE).abs() < epsilon);
+            assert!((up.len() - SignedFractional::ONE).abs() < epsilon);
+            assert!(right.dot(up).abs() < epsilon);
+            assert!(up.dot(forward).abs() < epsilon);
+            assert!(right.cross(up).approx_eq(forward, epsilon));
+            // Without twisting the frame keeps its angle to the plane of the curve
+            assert!((up.dot(Vec3::Z) - out_of_plane).abs() < epsilon);
+        }
+    }
+
+    #[test]
+    fn frames_of_degenerate_paths() {
+        assert!(parallel_transport_frames(&[]).is_empty());
+        assert!(parallel_transport_frames(&[Vec3::ONE]).is_empty());
+        assert!(parallel_transport_frames(&[Vec3::ONE, Vec3::ONE]).is_empty());
+
+        let frames = parallel_transport_frames(&[Vec3::ZERO, Vec3::X, Vec3::X, Vec3::new(2, 0, 0)]);
+        assert_eq!(frames.len(), 4);
+        assert!(frames.iter().all(|frame| *frame == frames[0]));
+    }
+}
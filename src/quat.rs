@@ -0,0 +1,336 @@
+use crate::vector::Vec3;
+use crate::{trig, SignedFractional};
+use fixed_sqrt::FixedSqrt;
+use std::ops::{Add, Mul};
+
+/// A quaternion describing a rotation in 3d
+///
+/// Rotations are represented by quaternions of unit length. Unlike euler angles they compose without gimbal lock and
+/// can be smoothly interpolated with [`slerp`](Quat::slerp).
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Quat {
+    /// The x component of the vector part
+    pub x: SignedFractional,
+    /// The y component of the vector part
+    pub y: SignedFractional,
+    /// The z component of the vector part
+    pub z: SignedFractional,
+    /// The scalar part
+    pub w: SignedFractional,
+}
+
+impl Quat {
+    /// The [`Quat`] not rotating at all
+    pub const IDENTITY: Self = Self::new(
+        SignedFractional::ZERO,
+        SignedFractional::ZERO,
+        SignedFractional::ZERO,
+        SignedFractional::ONE,
+    );
+
+    /// Creates a new [`Quat`] from its components
+    #[must_use]
+    pub const fn new(
+        x: SignedFractional,
+        y: SignedFractional,
+        z: SignedFractional,
+        w: SignedFractional,
+    ) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a [`Quat`] rotating counterclockwise by `radians` around `axis`, which has to be of unit length
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let q = Quat::from_axis_angle(Vec3::Z, SignedFractional::FRAC_PI_2);
+    ///
+    /// assert!(q.rotate_vec3(Vec3::X).approx_eq(Vec3::Y, SignedFractional::from_num(0.000_1)));
+    /// ```
+    #[must_use]
+    pub fn from_axis_angle(axis: Vec3, radians: SignedFractional) -> Self {
+        let (sin, cos) = trig::sin_cos(radians / 2);
+
+        Self::new(axis.x * sin, axis.y * sin, axis.z * sin, cos)
+    }
+
+    /// Returns the vector part of this [`Quat`]
+    fn xyz(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Returns the dot product of this [`Quat`] and `other`
+    ///
+    /// For unit quaternions it's the cosine of half of the angle between the two rotations.
+    #[must_use]
+    pub fn dot(&self, other: Self) -> SignedFractional {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns the conjugate of this [`Quat`], for unit quaternions the rotation undoing it
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let q = Quat::from_axis_angle(Vec3::Y, 1.into());
+    ///
+    /// assert_eq!(q.conjugate().rotate_vec3(q.rotate_vec3(Vec3::X)).x.round(), 1);
+    /// ```
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Sets the magnitude of this [`Quat`] to one
+    ///
+    /// Repeatedly composing rotations slowly accumulates rounding errors, normalizing brings the result back to a
+    /// valid rotation.
+    ///
+    /// # Panics
+    /// If the magnitude of the quaternion is zero
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// let mut q = Quat::new(0.into(), 0.into(), 0.into(), 3.into());
+    /// q.normalize();
+    ///
+    /// assert_eq!(q, Quat::IDENTITY);
+    /// ```
+    pub fn normalize(&mut self) {
+        let len = self.dot(*self).sqrt();
+
+        *self = Self::new(self.x / len, self.y / len, self.z / len, self.w / len);
+    }
+
+    /// Applies the rotation of this [`Quat`] to `v`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let q = Quat::from_axis_angle(Vec3::X, SignedFractional::PI);
+    ///
+    /// assert!(q.rotate_vec3(Vec3::new(1, 2, 3)).approx_eq(Vec3::new(1, -2, -3), SignedFractional::from_num(0.000_1)));
+    /// ```
+    #[must_use]
+    pub fn rotate_vec3(&self, v: Vec3) -> Vec3 {
+        let axis = self.xyz();
+        let t = axis.cross(v) * 2.into();
+
+        v + t * self.w + axis.cross(t)
+    }
+
+    /// Interpolates along the shortest arc between this [`Quat`] and `other` at a constant angular speed
+    ///
+    /// `t` of zero returns this [`Quat`] and one returns `other`, both have to be of unit length. Nearly equal
+    /// rotations fall back to a normalized linear interpolation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let half_turn = Quat::from_axis_angle(Vec3::Z, SignedFractional::PI);
+    /// let quarter_turn = Quat::IDENTITY.slerp(half_turn, SignedFractional::from_num(0.5));
+    ///
+    /// assert!(quarter_turn.rotate_vec3(Vec3::X).approx_eq(Vec3::Y, SignedFractional::from_num(0.000_1)));
+    /// ```
+    #[must_use]
+    pub fn slerp(&self, other: Self, t: SignedFractional) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+
+        // q and -q are the same rotation, flip one of them to take the shorter way around
+        if cos < SignedFractional::ZERO {
+            cos = -cos;
+            other = Self::new(-other.x, -other.y, -other.z, -other.w);
+        }
+
+        let sin = (SignedFractional::ONE - (cos * cos).min(SignedFractional::ONE)).sqrt();
+
+        if sin < SignedFractional::from_num(0.001) {
+            let mut lerped = *self * (SignedFractional::ONE - t) + other * t;
+            lerped.normalize();
+
+            return lerped;
+        }
+
+        let angle = trig::atan2(sin, cos);
+        let from = trig::sin((SignedFractional::ONE - t) * angle) / sin;
+        let to = trig::sin(t * angle) / sin;
+
+        *self * from + other * to
+    }
+
+    /// Returns `true` if all components of this [`Quat`] and `other` differ by at most `epsilon`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::quat::Quat;
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let q = Quat::from_axis_angle(Vec3::Y, 0.into());
+    ///
+    /// assert!(q.approx_eq(Quat::IDENTITY, SignedFractional::from_num(0.000_1)));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: Self, epsilon: SignedFractional) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+            && (self.w - other.w).abs() <= epsilon
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Composes two rotations, the result applies `rhs` first and `self` second
+impl Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl Mul<SignedFractional> for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: SignedFractional) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
+
+impl Add for Quat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::quat::Quat;
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn quarter_turns() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let quarter = SignedFractional::FRAC_PI_2;
+
+        let around_z = Quat::from_axis_angle(Vec3::Z, quarter);
+        assert!(around_z.rotate_vec3(Vec3::X).approx_eq(Vec3::Y, epsilon));
+        assert!(around_z.rotate_vec3(Vec3::Y).approx_eq(-Vec3::X, epsilon));
+        assert!(around_z.rotate_vec3(Vec3::Z).approx_eq(Vec3::Z, epsilon));
+
+        let around_x = Quat::from_axis_angle(Vec3::X, quarter);
+        assert!(around_x
+            .rotate_vec3(Vec3::new(4, 2, 3))
+            .approx_eq(Vec3::new(4, -3, 2), epsilon));
+
+        // Turning back with the conjugate or a negative angle
+        let back = Quat::from_axis_angle(Vec3::X, -quarter);
+        assert!(around_x.conjugate().approx_eq(back, epsilon));
+        assert!((around_x * back).approx_eq(Quat::IDENTITY, epsilon));
+        assert_eq!(
+            Quat::IDENTITY.rotate_vec3(Vec3::new(4, 2, 3)),
+            Vec3::new(4, 2, 3)
+        );
+        assert_eq!(Quat::default(), Quat::IDENTITY);
+    }
+
+    #[test]
+    fn composition_matches_sequential_rotation() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let first = Quat::from_axis_angle(Vec3::Y, SignedFractional::FRAC_PI_3);
+        let second = Quat::from_axis_angle(
+            Vec3::new(1, 1, 0).get_normalized(),
+            SignedFractional::from_num(-2),
+        );
+        let combined = second * first;
+
+        for v in [Vec3::X, Vec3::new(1, 2, 3), Vec3::new(-4, 0, 5)] {
+            let expected = second.rotate_vec3(first.rotate_vec3(v));
+
+            assert!(combined.rotate_vec3(v).approx_eq(expected, epsilon));
+        }
+
+        // Two quarter turns make a half turn
+        let quarter = Quat::from_axis_angle(Vec3::Z, SignedFractional::FRAC_PI_2);
+        assert!((quarter * quarter)
+            .rotate_vec3(Vec3::X)
+            .approx_eq(-Vec3::X, epsilon));
+    }
+
+    #[test]
+    fn normalization() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let mut q = Quat::new(1.into(), 2.into(), 2.into(), 4.into());
+
+        q.normalize();
+
+        assert!((q.dot(q) - SignedFractional::ONE).abs() < epsilon);
+        assert!(q.approx_eq(
+            Quat::new(
+                SignedFractional::from_num(0.2),
+                SignedFractional::from_num(0.4),
+                SignedFractional::from_num(0.4),
+                SignedFractional::from_num(0.8)
+            ),
+            epsilon
+        ));
+    }
+
+    #[test]
+    fn spherical_interpolation() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let from = Quat::from_axis_angle(Vec3::Y, SignedFractional::ZERO);
+        let to = Quat::from_axis_angle(Vec3::Y, SignedFractional::FRAC_PI_2);
+
+        assert!(from
+            .slerp(to, SignedFractional::ZERO)
+            .approx_eq(from, epsilon));
+        assert!(from.slerp(to, SignedFractional::ONE).approx_eq(to, epsilon));
+
+        // Constant angular speed, a third of the way is a third of the angle
+        let third = SignedFractional::ONE / SignedFractional::from_num(3);
+        let expected = Quat::from_axis_angle(Vec3::Y, SignedFractional::FRAC_PI_6);
+        assert!(from.slerp(to, third).approx_eq(expected, epsilon));
+
+        // The negated quaternion is the same rotation, the interpolation takes the short way around
+        let negated = to * SignedFractional::from_num(-1);
+        let halfway = from.slerp(negated, SignedFractional::from_num(0.5));
+        let expected = Quat::from_axis_angle(Vec3::Y, SignedFractional::FRAC_PI_4);
+        assert!(halfway
+            .rotate_vec3(Vec3::X)
+            .approx_eq(expected.rotate_vec3(Vec3::X), epsilon));
+
+        // Nearly equal rotations
+        let close = Quat::from_axis_angle(Vec3::Y, SignedFractional::from_num(0.000_1));
+        assert!(from
+            .slerp(close, SignedFractional::from_num(0.5))
+            .approx_eq(from, epsilon));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::aabb::Aabb3;
+use crate::vector::Vec3;
+use crate::SignedFractional;
+
+/// A half line in 3d starting at `origin` and extending along `direction`
+///
+/// Distances along the ray are measured in multiples of `direction`, they are only true distances when
+/// `direction` is a unit vector.
+#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+pub struct Ray3 {
+    /// The point the ray starts at
+    pub origin: Vec3,
+    /// The direction the ray extends in
+    pub direction: Vec3,
+}
+
+impl Ray3 {
+    /// Creates a new ray from its origin and direction
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::ray::Ray3;
+    /// # use skala_engine_numerics::Vec3;
+    /// let ray = Ray3::new(Vec3::ZERO, Vec3::X);
+    ///
+    /// assert_eq!(ray.direction, Vec3::X);
+    /// ```
+    #[must_use]
+    pub const fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Returns the point `distance` along the ray
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::ray::Ray3;
+    /// # use skala_engine_numerics::Vec3;
+    /// let ray = Ray3::new(Vec3::ONE, Vec3::Z);
+    ///
+    /// assert_eq!(ray.at(2.into()), Vec3::new(1, 1, 3));
+    /// ```
+    #[must_use]
+    pub fn at(&self, distance: SignedFractional) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Returns the distance along the ray at which it enters `aabb` or `None` if it misses the box
+    ///
+    /// Uses the slab method. Rays starting inside of the box enter it at a distance of zero and rays running
+    /// parallel to a pair of faces only hit the box when they start between those faces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::ray::Ray3;
+    /// # use skala_engine_numerics::{Aabb3, Vec3};
+    /// let aabb = Aabb3::new(Vec3::new(2, -1, -1), Vec3::new(4, 1, 1));
+    /// let ray = Ray3::new(Vec3::ZERO, Vec3::X);
+    ///
+    /// assert_eq!(ray.intersect_aabb(&aabb), Some(2.into()));
+    /// assert_eq!(Ray3::new(Vec3::ZERO, -Vec3::X).intersect_aabb(&aabb), None);
+    /// ```
+    #[must_use]
+    pub fn intersect_aabb(&self, aabb: &Aabb3) -> Option<SignedFractional> {
+        let slabs = [
+            (self.origin.x, self.direction.x, aabb.min.x, aabb.max.x),
+            (self.origin.y, self.direction.y, aabb.min.y, aabb.max.y),
+            (self.origin.z, self.direction.z, aabb.min.z, aabb.max.z),
+        ];
+
+        let mut near = SignedFractional::ZERO;
+        let mut far = SignedFractional::MAX;
+
+        for (origin, direction, min, max) in slabs {
+            if direction == SignedFractional::ZERO {
+                // Parallel to the slab, the ray is either always or never between its faces
+                if origin < min || max < origin {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let to_min = (min - origin).saturating_div(direction);
+            let to_max = (max - origin).saturating_div(direction);
+
+            near = near.max(to_min.min(to_max));
+            far = far.min(to_min.max(to_max));
+
+            if near > far {
+                return None;
+            }
+        }
+
+        Some(near)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aabb::Aabb3;
+    use crate::ray::Ray3;
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn hit() {
+        let aabb = Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(3, 3, 3));
+        let diagonal = Ray3::new(Vec3::ZERO, Vec3::ONE);
+        let from_above = Ray3::new(Vec3::new(2, 10, 2), Vec3::new(0, -2, 0));
+
+        assert_eq!(diagonal.intersect_aabb(&aabb), Some(1.into()));
+        assert_eq!(
+            from_above.intersect_aabb(&aabb),
+            Some(SignedFractional::from_num(3.5))
+        );
+        assert_eq!(
+            from_above.at(SignedFractional::from_num(3.5)),
+            Vec3::new(2, 3, 2)
+        );
+    }
+
+    #[test]
+    fn miss() {
+        let aabb = Aabb3::new(Vec3::new(1, 1, 1), Vec3::new(3, 3, 3));
+
+        // Parallel to the x slab but outside of it
+        assert_eq!(
+            Ray3::new(Vec3::new(0, 0, 2), Vec3::Y).intersect_aabb(&aabb),
+            None
+        );
+        // Passes beside the box
+        assert_eq!(
+            Ray3::new(Vec3::new(0, 0, 0), Vec3::new(1, 4, 0)).intersect_aabb(&aabb),
+            None
+        );
+        // Box lies behind the ray
+        assert_eq!(
+            Ray3::new(Vec3::new(5, 2, 2), Vec3::X).intersect_aabb(&aabb),
+            None
+        );
+    }
+
+    #[test]
+    fn origin_inside() {
+        let aabb = Aabb3::new(Vec3::new(-1, -1, -1), Vec3::ONE);
+
+        assert_eq!(
+            Ray3::new(Vec3::ZERO, Vec3::new(1, 2, 3)).intersect_aabb(&aabb),
+            Some(0.into())
+        );
+        assert_eq!(
+            Ray3::new(Vec3::ZERO, Vec3::ZERO).intersect_aabb(&aabb),
+            Some(0.into())
+        );
+    }
+}
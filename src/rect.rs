@@ -0,0 +1,213 @@
+use crate::vector::Vec2;
+
+/// A 2d rectangle described by its origin and size, meant for laying out UI and sprites
+///
+/// The origin is the corner with the smallest coordinates and the size is expected to be non negative.
+/// The rectangle is closed, points lying on its edges are considered to be inside of it.
+#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+pub struct Rect {
+    /// The corner with the smallest coordinates
+    pub origin: Vec2,
+    /// The width and height of the rectangle
+    pub size: Vec2,
+}
+
+impl Rect {
+    /// Creates a new rectangle from its origin and size
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let rect = Rect::new(Vec2::ONE, Vec2::new(4.into(), 2.into()));
+    ///
+    /// assert_eq!(rect.max(), Vec2::new(5.into(), 3.into()));
+    /// ```
+    #[must_use]
+    pub const fn new(origin: Vec2, size: Vec2) -> Self {
+        Self { origin, size }
+    }
+
+    /// Creates the rectangle spanning from the `min` corner to the `max` corner
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let rect = Rect::from_corners(Vec2::ONE, Vec2::new(4.into(), 2.into()));
+    ///
+    /// assert_eq!(rect.size, Vec2::new(3.into(), 1.into()));
+    /// ```
+    #[must_use]
+    pub fn from_corners(min: Vec2, max: Vec2) -> Self {
+        Self {
+            origin: min,
+            size: max - min,
+        }
+    }
+
+    /// Returns the corner with the smallest coordinates, same as [`origin`](Self::origin)
+    #[must_use]
+    pub const fn min(&self) -> Vec2 {
+        self.origin
+    }
+
+    /// Returns the corner with the largest coordinates
+    #[must_use]
+    pub fn max(&self) -> Vec2 {
+        self.origin + self.size
+    }
+
+    /// Returns all four corners of the rectangle counterclockwise starting from [`min`](Self::min)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let rect = Rect::new(Vec2::ZERO, Vec2::new(2.into(), 1.into()));
+    ///
+    /// assert_eq!(
+    ///     rect.corners(),
+    ///     [
+    ///         Vec2::ZERO,
+    ///         Vec2::new(2.into(), 0.into()),
+    ///         Vec2::new(2.into(), 1.into()),
+    ///         Vec2::new(0.into(), 1.into()),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn corners(&self) -> [Vec2; 4] {
+        let min = self.min();
+        let max = self.max();
+
+        [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)]
+    }
+
+    /// Checks whether `point` lies inside of the rectangle or on its boundary
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let rect = Rect::new(Vec2::ZERO, Vec2::new(2.into(), 2.into()));
+    ///
+    /// assert!(rect.contains(Vec2::ONE));
+    /// assert!(rect.contains(Vec2::new(2.into(), 0.into())));
+    /// assert!(!rect.contains(Vec2::new(3.into(), 0.into())));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, point: Vec2) -> bool {
+        let max = self.max();
+
+        self.origin.x <= point.x && point.x <= max.x && self.origin.y <= point.y && point.y <= max.y
+    }
+
+    /// Returns the area shared by both rectangles or `None` if they don't overlap
+    ///
+    /// Rectangles touching only along an edge produce a rectangle with zero width or height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::ZERO, Vec2::new(2.into(), 2.into()));
+    /// let b = Rect::new(Vec2::ONE, Vec2::new(2.into(), 2.into()));
+    ///
+    /// assert_eq!(a.intersection(b), Some(Rect::new(Vec2::ONE, Vec2::ONE)));
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        let min = self.min().max(other.min());
+        let max = self.max().min(other.max());
+
+        if min.x > max.x || min.y > max.y {
+            return None;
+        }
+
+        Some(Self::from_corners(min, max))
+    }
+
+    /// Returns the smallest rectangle containing both rectangles
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Rect, Vec2};
+    /// let a = Rect::new(Vec2::ZERO, Vec2::ONE);
+    /// let b = Rect::new(Vec2::new(2.into(), 3.into()), Vec2::ONE);
+    ///
+    /// assert_eq!(a.union(b), Rect::new(Vec2::ZERO, Vec2::new(3.into(), 4.into())));
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: Self) -> Self {
+        Self::from_corners(self.min().min(other.min()), self.max().max(other.max()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::rect::Rect;
+    use crate::vector::Vec2;
+
+    #[test]
+    fn intersection_is_smaller() {
+        let a = Rect::new(Vec2::ZERO, Vec2::new(4.into(), 4.into()));
+        let b = Rect::new(Vec2::new(1.into(), 2.into()), Vec2::new(6.into(), 1.into()));
+
+        let expected = Rect::new(Vec2::new(1.into(), 2.into()), Vec2::new(3.into(), 1.into()));
+
+        assert_eq!(a.intersection(b), Some(expected));
+        assert_eq!(b.intersection(a), Some(expected));
+    }
+
+    #[test]
+    fn disjoint_intersection() {
+        let a = Rect::new(Vec2::ZERO, Vec2::ONE);
+        let b = Rect::new(Vec2::new(2.into(), 0.into()), Vec2::ONE);
+        let touching = Rect::new(Vec2::new(1.into(), 0.into()), Vec2::ONE);
+
+        assert_eq!(a.intersection(b), None);
+        assert_eq!(
+            a.intersection(touching),
+            Some(Rect::new(
+                Vec2::new(1.into(), 0.into()),
+                Vec2::new(0.into(), 1.into())
+            ))
+        );
+    }
+
+    #[test]
+    fn edge_containment() {
+        let rect = Rect::new(
+            Vec2::new((-1).into(), 1.into()),
+            Vec2::new(3.into(), 2.into()),
+        );
+
+        assert!(rect.corners().iter().all(|corner| rect.contains(*corner)));
+        assert!(rect.contains(Vec2::new(0.into(), 3.into())));
+        assert!(!rect.contains(Vec2::new(0.into(), 4.into())));
+        assert!(!rect.contains(Vec2::new((-2).into(), 2.into())));
+    }
+
+    #[test]
+    fn union_covers_both() {
+        let a = Rect::new(Vec2::new((-1).into(), 0.into()), Vec2::ONE);
+        let b = Rect::new(Vec2::new(3.into(), 2.into()), Vec2::new(1.into(), 2.into()));
+
+        let union = a.union(b);
+
+        assert_eq!(
+            union,
+            Rect::from_corners(
+                Vec2::new((-1).into(), 0.into()),
+                Vec2::new(4.into(), 4.into())
+            )
+        );
+        assert!(a
+            .corners()
+            .iter()
+            .chain(b.corners().iter())
+            .all(|corner| union.contains(*corner)));
+    }
+}
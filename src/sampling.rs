@@ -0,0 +1,195 @@
+use crate::spatial::grid_points_2d;
+use crate::vector::{Vec2, Vec3};
+use crate::{trig, SignedFractional};
+use fixed_sqrt::FixedSqrt;
+
+/// Maps two uniformly distributed numbers from `[0, 1]` to a uniformly distributed point inside of the triangle
+/// `a`, `b`, `c`
+///
+/// Taking the square root of `u` compensates for the triangle getting narrower towards `a`, without it points
+/// would bunch up near `a`.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sampling::sample_triangle;
+/// # use skala_engine_numerics::Vec3;
+/// let a = Vec3::ZERO;
+/// let b = Vec3::new(2, 0, 0);
+/// let c = Vec3::new(0, 2, 0);
+///
+/// assert_eq!(sample_triangle(a, b, c, 0.into(), 0.into()), a);
+/// assert_eq!(sample_triangle(a, b, c, 1.into(), 0.into()), b);
+/// assert_eq!(sample_triangle(a, b, c, 1.into(), 1.into()), c);
+/// ```
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn sample_triangle(
+    a: Vec3,
+    b: Vec3,
+    c: Vec3,
+    u: SignedFractional,
+    v: SignedFractional,
+) -> Vec3 {
+    let root_u = u.sqrt();
+
+    a * (SignedFractional::ONE - root_u)
+        + b * (root_u * (SignedFractional::ONE - v))
+        + c * (root_u * v)
+}
+
+/// Maps two uniformly distributed numbers from `[0, 1]` to a cosine weighted unit direction in the hemisphere
+/// around the unit vector `normal`
+///
+/// Directions close to `normal` are more likely than directions grazing the surface, matching how much light
+/// arriving from them contributes to a diffuse surface. A `u` of zero gives `normal` itself and a `u` of one a
+/// direction perpendicular to it.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sampling::sample_hemisphere;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let direction = sample_hemisphere(Vec3::Y, SignedFractional::from_num(0.3), SignedFractional::from_num(0.7));
+///
+/// assert!(direction.dot(Vec3::Y) > 0);
+/// ```
+#[must_use]
+pub fn sample_hemisphere(normal: Vec3, u: SignedFractional, v: SignedFractional) -> Vec3 {
+    let (tangent, bitangent) = normal.any_orthonormal_basis();
+
+    // Uniformly sample a disc and project it up onto the hemisphere
+    let radius = u.sqrt();
+    let (sin, cos) = trig::sin_cos(SignedFractional::TAU * v);
+    let height = (SignedFractional::ONE - u).sqrt();
+
+    tangent * (radius * cos) + bitangent * (radius * sin) + normal * height
+}
+
+/// Places one point in every cell of a `cols` by `rows` grid starting at `origin` with cells `step` wide, each
+/// moved within its cell by the matching entry of `offsets`
+///
+/// Offsets are fractions of a cell from `[0, 1)` on both axes, listed in the same row by row order as
+/// [`grid_points_2d`](crate::spatial::grid_points_2d) returns the cells in. Filling `offsets` with random numbers
+/// gives stratified samples, which cover an area more evenly than purely random points.
+///
+/// # Panics
+/// When `offsets` doesn't contain exactly `cols * rows` entries
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sampling::jitter_grid;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let half = SignedFractional::from_num(0.5);
+/// let offsets = [Vec2::new(half, half), Vec2::new(0.into(), half)];
+///
+/// let points = jitter_grid(Vec2::ZERO, Vec2::new(2.into(), 2.into()), 2, 1, &offsets);
+///
+/// assert_eq!(points, vec![Vec2::ONE, Vec2::new(2.into(), 1.into())]);
+/// ```
+#[must_use]
+pub fn jitter_grid(
+    origin: Vec2,
+    step: Vec2,
+    cols: usize,
+    rows: usize,
+    offsets: &[Vec2],
+) -> Vec<Vec2> {
+    assert_eq!(
+        offsets.len(),
+        cols * rows,
+        "Jittered grid needs exactly one offset per cell"
+    );
+
+    grid_points_2d(origin, step, cols, rows)
+        .into_iter()
+        .zip(offsets)
+        .map(|(corner, offset)| corner + Vec2::new(step.x * offset.x, step.y * offset.y))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sampling::{jitter_grid, sample_hemisphere, sample_triangle};
+    use crate::spatial::grid_points_2d;
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn triangle_samples_center_on_centroid() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(6, 0, 3);
+        let c = Vec3::new(0, 9, -3);
+        let centroid = Vec3::new(2, 3, 0);
+        let epsilon = SignedFractional::from_num(0.05);
+
+        // Stratified samples spread evenly over the unit square behave like uniform random ones
+        let resolution = 32;
+        let step = SignedFractional::ONE / SignedFractional::from_num(resolution);
+        let half_step = step / SignedFractional::from_num(2);
+
+        let mut sum = Vec3::ZERO;
+
+        for row in 0..resolution {
+            for column in 0..resolution {
+                let row = step * SignedFractional::from_num(row) + half_step;
+                let column = step * SignedFractional::from_num(column) + half_step;
+
+                sum += sample_triangle(a, b, c, row, column);
+            }
+        }
+
+        let mean = sum / SignedFractional::from_num(resolution * resolution);
+
+        assert!(mean.approx_eq(centroid, epsilon), "{mean:?}");
+    }
+
+    #[test]
+    fn hemisphere_samples_face_normal() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let normals = [Vec3::Z, -Vec3::X, Vec3::new(1, -2, 2).get_normalized()];
+        let resolution = 16;
+        let step = SignedFractional::ONE / SignedFractional::from_num(resolution);
+        let half_step = step / SignedFractional::from_num(2);
+
+        for normal in normals {
+            for row in 0..resolution {
+                for column in 0..resolution {
+                    let row = step * SignedFractional::from_num(row) + half_step;
+                    let column = step * SignedFractional::from_num(column) + half_step;
+
+                    let direction = sample_hemisphere(normal, row, column);
+
+                    assert!(direction.dot(normal) > SignedFractional::ZERO);
+                    assert!((direction.len() - SignedFractional::ONE).abs() < epsilon);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn jitter_without_offsets_is_grid() {
+        let origin = Vec2::new((-3).into(), 1.into());
+        let step = Vec2::new(2.into(), SignedFractional::from_num(0.5));
+
+        let points = jitter_grid(origin, step, 4, 3, &[Vec2::ZERO; 12]);
+
+        assert_eq!(points, grid_points_2d(origin, step, 4, 3));
+    }
+
+    #[test]
+    fn jitter_stays_in_cells() {
+        let step = Vec2::new(2.into(), 4.into());
+        let almost_one = SignedFractional::ONE - SignedFractional::DELTA;
+        let offsets = [Vec2::new(almost_one, almost_one); 6];
+
+        let points = jitter_grid(Vec2::ZERO, step, 3, 2, &offsets);
+        let corners = grid_points_2d(Vec2::ZERO, step, 3, 2);
+
+        for (point, corner) in points.iter().zip(corners) {
+            assert!(corner.x <= point.x && point.x < corner.x + step.x);
+            assert!(corner.y <= point.y && point.y < corner.y + step.y);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::vector::{Vec2, Vec3};
+use crate::SignedFractional;
+
+/// Returns the signed distance from `p` to the edge of a circle, negative inside of the circle
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_circle;
+/// # use skala_engine_numerics::Vec2;
+/// let center = Vec2::new(1.into(), 1.into());
+///
+/// assert_eq!(sdf_circle(center, center, 2.into()), -2);
+/// assert_eq!(sdf_circle(Vec2::new(4.into(), 5.into()), center, 2.into()), 3);
+/// ```
+#[must_use]
+pub fn sdf_circle(p: Vec2, center: Vec2, radius: SignedFractional) -> SignedFractional {
+    (p - center).len() - radius
+}
+
+/// Returns the signed distance from `p` to the edge of an axis aligned box reaching `half_extents` away from `center`
+/// on each axis, negative inside of the box
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_box;
+/// # use skala_engine_numerics::Vec2;
+/// let half_extents = Vec2::new(2.into(), 1.into());
+///
+/// assert_eq!(sdf_box(Vec2::ZERO, Vec2::ZERO, half_extents), -1);
+/// assert_eq!(sdf_box(Vec2::new(5.into(), 0.into()), Vec2::ZERO, half_extents), 3);
+/// ```
+#[must_use]
+pub fn sdf_box(p: Vec2, center: Vec2, half_extents: Vec2) -> SignedFractional {
+    // Distance past each pair of edges, negative when between them
+    let q = (p - center).abs() - half_extents;
+
+    let outside = q.max(Vec2::ZERO).len();
+    let inside = q.x.max(q.y).min(SignedFractional::ZERO);
+
+    outside + inside
+}
+
+/// Combines two signed distances into the distance to the union of their shapes
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_union;
+/// assert_eq!(sdf_union((-1).into(), 2.into()), -1);
+/// ```
+#[must_use]
+pub fn sdf_union(a: SignedFractional, b: SignedFractional) -> SignedFractional {
+    a.min(b)
+}
+
+/// Combines two signed distances into the distance to the overlap of their shapes
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_intersect;
+/// assert_eq!(sdf_intersect((-1).into(), 2.into()), 2);
+/// ```
+#[must_use]
+pub fn sdf_intersect(a: SignedFractional, b: SignedFractional) -> SignedFractional {
+    a.max(b)
+}
+
+/// Combines two signed distances into the distance to the shape of `a` with the shape of `b` cut out of it
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_subtract;
+/// // Inside of both shapes is outside of the result
+/// assert_eq!(sdf_subtract((-3).into(), (-1).into()), 1);
+/// ```
+#[must_use]
+pub fn sdf_subtract(a: SignedFractional, b: SignedFractional) -> SignedFractional {
+    a.max(-b)
+}
+
+/// Combines two signed distances into the distance to the union of their shapes with the seam rounded off over a
+/// distance of `k`
+///
+/// Uses the polynomial smooth minimum, a `k` of zero gives the same result as [`sdf_union`].
+///
+/// # Panics
+/// When `k` is negative
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::sdf_smooth_union;
+/// # use skala_engine_numerics::SignedFractional;
+/// let blended = sdf_smooth_union(1.into(), 1.into(), 2.into());
+///
+/// assert_eq!(blended, SignedFractional::from_num(0.5));
+/// // Far from the seam the smaller distance wins unchanged
+/// assert_eq!(sdf_smooth_union(1.into(), 5.into(), 2.into()), 1);
+/// ```
+#[must_use]
+pub fn sdf_smooth_union(
+    a: SignedFractional,
+    b: SignedFractional,
+    k: SignedFractional,
+) -> SignedFractional {
+    assert!(
+        k >= SignedFractional::ZERO,
+        "Smoothing distance can't be negative"
+    );
+
+    if k == SignedFractional::ZERO {
+        return sdf_union(a, b);
+    }
+
+    let half = SignedFractional::from_num(0.5);
+    let h = (half + half * (b - a) / k).clamp(SignedFractional::ZERO, SignedFractional::ONE);
+
+    b + (a - b) * h - k * h * (SignedFractional::ONE - h)
+}
+
+/// Combines two signed distances into the distance to the overlap of their shapes with the seam rounded off over a
+/// distance of `k`
+///
+/// # Panics
+/// When `k` is negative
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::{sdf_intersect, sdf_smooth_intersect};
+/// assert!(sdf_smooth_intersect(1.into(), 1.into(), 2.into()) > sdf_intersect(1.into(), 1.into()));
+/// ```
+#[must_use]
+pub fn sdf_smooth_intersect(
+    a: SignedFractional,
+    b: SignedFractional,
+    k: SignedFractional,
+) -> SignedFractional {
+    -sdf_smooth_union(-a, -b, k)
+}
+
+/// Combines two signed distances into the distance to the shape of `a` with the shape of `b` cut out of it and the
+/// seam rounded off over a distance of `k`
+///
+/// # Panics
+/// When `k` is negative
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::{sdf_smooth_subtract, sdf_subtract};
+/// assert_eq!(sdf_smooth_subtract((-4).into(), (-1).into(), 0.into()), sdf_subtract((-4).into(), (-1).into()));
+/// ```
+#[must_use]
+pub fn sdf_smooth_subtract(
+    a: SignedFractional,
+    b: SignedFractional,
+    k: SignedFractional,
+) -> SignedFractional {
+    sdf_smooth_intersect(a, -b, k)
+}
+
+/// Estimates the outward unit normal of the surface described by the signed distance function `f` at `p`
+///
+/// The gradient is taken by central differences `eps` away from `p` along each axis. Returns a zero vector where
+/// the gradient vanishes, like at the center of a circle.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::{sdf_circle, sdf_normal};
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let circle = |p| sdf_circle(p, Vec2::ZERO, 2.into());
+/// let eps = SignedFractional::from_num(0.001);
+/// let normal = sdf_normal(Vec2::new(0.into(), 2.into()), circle, eps);
+///
+/// assert!(normal.approx_eq(Vec2::Y, SignedFractional::from_num(0.000_01)));
+/// ```
+#[must_use]
+pub fn sdf_normal<F: Fn(Vec2) -> SignedFractional>(p: Vec2, f: F, eps: SignedFractional) -> Vec2 {
+    let dx = Vec2::new(eps, SignedFractional::ZERO);
+    let dy = Vec2::new(SignedFractional::ZERO, eps);
+
+    Vec2::new(f(p + dx) - f(p - dx), f(p + dy) - f(p - dy)).normalize_or_zero()
+}
+
+/// Marches a ray from `origin` along the unit direction `dir` through the signed distance function `sdf` using sphere
+/// tracing and returns the point where it hits the surface
+///
+/// Every step advances the ray by the distance `sdf` reports, which can't overshoot the surface. The surface counts
+/// as hit once the distance drops below `surface_eps`. Returns `None` when the ray travels further than `max_dist`
+/// or doesn't hit anything within `max_steps` steps.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::sdf::ray_march;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let sphere = |p: Vec3| (p - Vec3::new(0, 0, 10)).len() - SignedFractional::from_num(2);
+/// let eps = SignedFractional::from_num(0.001);
+///
+/// let hit = ray_march(Vec3::ZERO, Vec3::Z, sphere, 64, 100.into(), eps).unwrap();
+///
+/// assert!(hit.approx_eq(Vec3::new(0, 0, 8), eps));
+/// assert_eq!(ray_march(Vec3::ZERO, -Vec3::Z, sphere, 64, 100.into(), eps), None);
+/// ```
+#[must_use]
+pub fn ray_march<F: Fn(Vec3) -> SignedFractional>(
+    origin: Vec3,
+    dir: Vec3,
+    sdf: F,
+    max_steps: u32,
+    max_dist: SignedFractional,
+    surface_eps: SignedFractional,
+) -> Option<Vec3> {
+    let mut traveled = SignedFractional::ZERO;
+
+    for _ in 0..max_steps {
+        let point = origin + dir * traveled;
+        let distance = sdf(point);
+
+        if distance < surface_eps {
+            return Some(point);
+        }
+
+        traveled += distance;
+
+        if traveled > max_dist {
+            return None;
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sdf::{
+        ray_march, sdf_box, sdf_circle, sdf_intersect, sdf_normal, sdf_smooth_intersect,
+        sdf_smooth_subtract, sdf_smooth_union, sdf_subtract, sdf_union,
+    };
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn circle() {
+        let center = Vec2::new((-2).into(), 3.into());
+        let radius = SignedFractional::from_num(1.5);
+
+        assert_eq!(sdf_circle(center, center, radius), -radius);
+        assert_eq!(
+            sdf_circle(Vec2::new(1.into(), 7.into()), center, radius),
+            SignedFractional::from_num(3.5)
+        );
+        assert_eq!(
+            sdf_circle(center + Vec2::new(radius, 0.into()), center, radius),
+            0
+        );
+    }
+
+    #[test]
+    fn box_distance() {
+        let center = Vec2::new(1.into(), 1.into());
+        let half_extents = Vec2::new(3.into(), 2.into());
+
+        assert_eq!(sdf_box(center, center, half_extents), -2);
+        // Beside an edge the distance is straight to that edge
+        assert_eq!(
+            sdf_box(Vec2::new(1.into(), 8.into()), center, half_extents),
+            5
+        );
+        // Past a corner the distance is to the corner
+        assert_eq!(
+            sdf_box(Vec2::new(7.into(), 7.into()), center, half_extents),
+            5
+        );
+        assert_eq!(
+            sdf_box(Vec2::new(4.into(), 0.into()), center, half_extents),
+            0
+        );
+    }
+
+    #[test]
+    fn hard_combinations() {
+        let circle = sdf_circle(Vec2::new(3.into(), 0.into()), Vec2::ZERO, 2.into());
+        let square = sdf_box(Vec2::new(3.into(), 0.into()), Vec2::ZERO, Vec2::ONE);
+
+        assert_eq!(circle, 1);
+        assert_eq!(square, 2);
+        assert_eq!(sdf_union(circle, square), 1);
+        assert_eq!(sdf_intersect(circle, square), 2);
+        assert_eq!(sdf_subtract(circle, square), 1);
+        assert_eq!(sdf_subtract((-1).into(), (-2).into()), 2);
+    }
+
+    #[test]
+    fn smooth_union_rounds_seam() {
+        let k = SignedFractional::from_num(2);
+
+        // Near the seam the blend bulges outwards, giving smaller distances than the hard union
+        for (a, b) in [(1, 1), (1, 2), (0, -1)] {
+            let (a, b) = (SignedFractional::from_num(a), SignedFractional::from_num(b));
+
+            assert!(sdf_smooth_union(a, b, k) < sdf_union(a, b));
+            assert!(sdf_smooth_intersect(a, b, k) > sdf_intersect(a, b));
+        }
+
+        // Further apart than k the smooth union is the hard one
+        assert_eq!(sdf_smooth_union(1.into(), 4.into(), k), 1);
+        assert_eq!(sdf_smooth_union(4.into(), 1.into(), k), 1);
+        assert_eq!(sdf_smooth_union(1.into(), 2.into(), 0.into()), 1);
+        assert_eq!(
+            sdf_smooth_subtract((-4).into(), 1.into(), k),
+            sdf_subtract((-4).into(), 1.into())
+        );
+    }
+
+    #[test]
+    fn circle_normals_point_outwards() {
+        let center = Vec2::new(1.into(), (-1).into());
+        let circle = |p| sdf_circle(p, center, 5.into());
+        let eps = SignedFractional::from_num(0.001);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        for offset in [
+            Vec2::new(3.into(), 4.into()),
+            Vec2::new((-4).into(), 3.into()),
+            Vec2::new(0.into(), (-5).into()),
+            Vec2::new(6.into(), (-8).into()),
+        ] {
+            let normal = sdf_normal(center + offset, circle, eps);
+
+            assert!(normal.approx_eq(offset.get_normalized(), epsilon));
+        }
+
+        assert_eq!(sdf_normal(center, circle, eps), Vec2::ZERO);
+    }
+
+    #[test]
+    fn ray_march_hits_sphere() {
+        let center = Vec3::new(3, -2, 12);
+        let radius = SignedFractional::from_num(4);
+        let sphere = |p: Vec3| (p - center).len() - radius;
+        let eps = SignedFractional::from_num(0.001);
+        let origin = Vec3::new(3, -2, 0);
+
+        let hit = ray_march(origin, Vec3::Z, sphere, 64, 100.into(), eps).unwrap();
+
+        assert!(sphere(hit).abs() < eps);
+        assert!(hit.approx_eq(Vec3::new(3, -2, 8), eps));
+
+        // An oblique ray still lands on the surface
+        let dir = (center - origin + Vec3::new(2, 0, 0)).get_normalized();
+        let hit = ray_march(origin, dir, sphere, 64, 100.into(), eps).unwrap();
+
+        assert!(sphere(hit).abs() < eps);
+    }
+
+    #[test]
+    fn ray_march_misses() {
+        let sphere = |p: Vec3| (p - Vec3::new(0, 0, 10)).len() - SignedFractional::ONE;
+        let eps = SignedFractional::from_num(0.001);
+
+        assert_eq!(
+            ray_march(Vec3::ZERO, Vec3::X, sphere, 64, 50.into(), eps),
+            None
+        );
+        // Too short to reach the sphere
+        assert_eq!(
+            ray_march(Vec3::ZERO, Vec3::Z, sphere, 64, 5.into(), eps),
+            None
+        );
+        // Too few steps to converge at a grazing angle
+        let grazing = Vec3::new(1, 0, 10).get_normalized();
+        assert_eq!(
+            ray_march(Vec3::ZERO, grazing, sphere, 1, 50.into(), eps),
+            None
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::vector::Vec3;
+use crate::SignedFractional;
+
+/// Returns the half vector used by Blinn-Phong specular lighting, the unit direction halfway between the unit
+/// directions `view` and `light`
+///
+/// Both directions point away from the shaded surface. Returns a zero vector when they point in opposite directions.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::shading::half_vector;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let view = Vec3::new(0, 3, 4).get_normalized();
+/// let light = Vec3::new(0, 3, -4).get_normalized();
+/// let epsilon = SignedFractional::from_num(0.000_01);
+///
+/// assert!(half_vector(view, light).approx_eq(Vec3::Y, epsilon));
+/// assert_eq!(half_vector(view, -view), Vec3::ZERO);
+/// ```
+#[must_use]
+pub fn half_vector(view: Vec3, light: Vec3) -> Vec3 {
+    (view + light).normalize_or_zero()
+}
+
+/// Returns the fraction of light reflected off a surface using Schlick's approximation of the Fresnel equations
+///
+/// `cos_theta` is the cosine of the angle between the surface normal and the view direction, usually their dot
+/// product, and is clamped to `[0, 1]`. `f0` is the reflectance when looking straight at the surface, reflectance
+/// grows towards one as the view approaches grazing angles.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::shading::fresnel_schlick;
+/// # use skala_engine_numerics::SignedFractional;
+/// let f0 = SignedFractional::from_num(0.04);
+///
+/// assert_eq!(fresnel_schlick(1.into(), f0), f0);
+/// assert_eq!(fresnel_schlick(0.into(), f0), 1);
+/// ```
+#[must_use]
+pub fn fresnel_schlick(cos_theta: SignedFractional, f0: SignedFractional) -> SignedFractional {
+    let falloff =
+        SignedFractional::ONE - cos_theta.clamp(SignedFractional::ZERO, SignedFractional::ONE);
+    let falloff_pow2 = falloff * falloff;
+
+    f0 + (SignedFractional::ONE - f0) * falloff_pow2 * falloff_pow2 * falloff
+}
+
+#[cfg(test)]
+mod test {
+    use crate::shading::{fresnel_schlick, half_vector};
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn symmetric_directions_bisector() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let view = Vec3::new(3, 4, 0).get_normalized();
+        let light = Vec3::new(-3, 4, 0).get_normalized();
+
+        assert!(half_vector(view, light).approx_eq(Vec3::Y, epsilon));
+        assert!(half_vector(Vec3::X, Vec3::Z).approx_eq(
+            Vec3::new(
+                SignedFractional::SQRT_2 / SignedFractional::from_num(2),
+                0,
+                SignedFractional::SQRT_2 / SignedFractional::from_num(2)
+            ),
+            epsilon
+        ));
+    }
+
+    #[test]
+    fn opposite_directions_cancel() {
+        let view = Vec3::new(1, 2, 2).get_normalized();
+
+        assert_eq!(half_vector(view, -view), Vec3::ZERO);
+    }
+
+    #[test]
+    fn fresnel_normal_incidence() {
+        let f0 = SignedFractional::from_num(0.04);
+
+        assert_eq!(fresnel_schlick(SignedFractional::ONE, f0), f0);
+        // Cosines above one are clamped
+        assert_eq!(fresnel_schlick(SignedFractional::from_num(1.5), f0), f0);
+    }
+
+    #[test]
+    fn fresnel_grazing_angle() {
+        let f0 = SignedFractional::from_num(0.04);
+        let epsilon = SignedFractional::from_num(0.01);
+
+        assert_eq!(fresnel_schlick(SignedFractional::ZERO, f0), 1);
+        assert_eq!(fresnel_schlick(SignedFractional::from_num(-0.5), f0), 1);
+        assert!(
+            SignedFractional::ONE - fresnel_schlick(SignedFractional::from_num(0.001), f0)
+                < epsilon
+        );
+
+        let half = fresnel_schlick(SignedFractional::from_num(0.5), f0);
+        assert!(f0 < half && half < SignedFractional::ONE);
+        assert_eq!(
+            half,
+            f0 + (SignedFractional::ONE - f0) / SignedFractional::from_num(32)
+        );
+    }
+}
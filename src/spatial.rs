@@ -0,0 +1,500 @@
+use crate::aabb::Aabb2;
+use crate::vector::{Vec2, Vec3};
+use crate::SignedFractional;
+use std::collections::HashMap;
+
+/// Returns the coordinates of the cell containing `point` in a grid of square cells `cell_size` units wide
+///
+/// Cell `(0, 0)` spans from the origin up to (but not including) `cell_size` on both axes.
+///
+/// # Panics
+/// When `cell_size` is zero or the cell coordinates don't fit into an `i32`
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::spatial::grid_cell;
+/// # use skala_engine_numerics::Vec2;
+/// assert_eq!(grid_cell(Vec2::new(5.into(), (-1).into()), 2.into()), (2, -1));
+/// ```
+#[must_use]
+pub fn grid_cell(point: Vec2, cell_size: SignedFractional) -> (i32, i32) {
+    (
+        (point.x / cell_size).floor().to_num(),
+        (point.y / cell_size).floor().to_num(),
+    )
+}
+
+/// Returns a regular grid of `cols` by `rows` points starting at `origin` and spaced `step` apart
+///
+/// Points are ordered row by row, `origin + step * (col, row)` lands at index `row * cols + col`.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::spatial::grid_points_2d;
+/// # use skala_engine_numerics::Vec2;
+/// let points = grid_points_2d(Vec2::ZERO, Vec2::new(2.into(), 1.into()), 3, 2);
+///
+/// assert_eq!(points.len(), 6);
+/// assert_eq!(points[1], Vec2::new(2.into(), 0.into()));
+/// assert_eq!(points[5], Vec2::new(4.into(), 1.into()));
+/// ```
+#[must_use]
+pub fn grid_points_2d(origin: Vec2, step: Vec2, cols: usize, rows: usize) -> Vec<Vec2> {
+    (0..rows)
+        .flat_map(|row| {
+            (0..cols).map(move |col| {
+                origin
+                    + Vec2::new(
+                        step.x * SignedFractional::from_num(col),
+                        step.y * SignedFractional::from_num(row),
+                    )
+            })
+        })
+        .collect()
+}
+
+/// Returns a regular lattice of `cols` by `rows` by `layers` points starting at `origin` and spaced `step` apart
+///
+/// Points are ordered layer by layer and row by row within a layer, `origin + step * (col, row, layer)` lands at
+/// index `(layer * rows + row) * cols + col`.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::spatial::grid_points_3d;
+/// # use skala_engine_numerics::Vec3;
+/// let points = grid_points_3d(Vec3::ZERO, Vec3::new(1, 2, 3), 2, 2, 2);
+///
+/// assert_eq!(points.len(), 8);
+/// assert_eq!(points[4], Vec3::new(0, 0, 3));
+/// assert_eq!(points[7], Vec3::new(1, 2, 3));
+/// ```
+#[must_use]
+pub fn grid_points_3d(
+    origin: Vec3,
+    step: Vec3,
+    cols: usize,
+    rows: usize,
+    layers: usize,
+) -> Vec<Vec3> {
+    (0..layers)
+        .flat_map(|layer| {
+            (0..rows).flat_map(move |row| {
+                (0..cols).map(move |col| {
+                    origin
+                        + Vec3::new(
+                            step.x * SignedFractional::from_num(col),
+                            step.y * SignedFractional::from_num(row),
+                            step.z * SignedFractional::from_num(layer),
+                        )
+                })
+            })
+        })
+        .collect()
+}
+
+/// A uniform grid bucketing 2d points by the cell they fall into
+///
+/// Useful for finding neighbours of particles or boids without checking every pair of points
+#[derive(Debug, Clone)]
+pub struct SpatialHash2<T> {
+    cell_size: SignedFractional,
+    cells: HashMap<(i32, i32), Vec<(Vec2, T)>>,
+    len: usize,
+}
+
+impl<T> SpatialHash2<T> {
+    /// Creates an empty spatial hash with square cells `cell_size` units wide
+    ///
+    /// Cells roughly as big as the usual query radius work best
+    ///
+    /// # Panics
+    /// When `cell_size` isn't positive
+    #[must_use]
+    pub fn new(cell_size: SignedFractional) -> Self {
+        assert!(
+            cell_size > SignedFractional::ZERO,
+            "Spatial hash cell size has to be positive"
+        );
+
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the width of the cells
+    #[must_use]
+    pub fn cell_size(&self) -> SignedFractional {
+        self.cell_size
+    }
+
+    /// Returns the number of stored points
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no points are stored
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stores `value` at `point`
+    pub fn insert(&mut self, point: Vec2, value: T) {
+        self.cells
+            .entry(grid_cell(point, self.cell_size))
+            .or_default()
+            .push((point, value));
+        self.len += 1;
+    }
+
+    /// Returns every stored point no further than `radius` from `center` together with its value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::spatial::SpatialHash2;
+    /// # use skala_engine_numerics::Vec2;
+    /// let mut hash = SpatialHash2::new(4.into());
+    /// hash.insert(Vec2::new(1.into(), 1.into()), "near");
+    /// hash.insert(Vec2::new(30.into(), 1.into()), "far");
+    ///
+    /// let found = hash.query_radius(Vec2::ZERO, 5.into());
+    ///
+    /// assert_eq!(found, vec![(Vec2::new(1.into(), 1.into()), &"near")]);
+    /// ```
+    #[must_use]
+    pub fn query_radius(&self, center: Vec2, radius: SignedFractional) -> Vec<(Vec2, &T)> {
+        let reach = Vec2::new(radius, radius);
+        let (min_x, min_y) = grid_cell(center - reach, self.cell_size);
+        let (max_x, max_y) = grid_cell(center + reach, self.cell_size);
+        let radius_pow2 = radius * radius;
+
+        let mut found = Vec::new();
+
+        for cell_x in min_x..=max_x {
+            for cell_y in min_y..=max_y {
+                let Some(cell) = self.cells.get(&(cell_x, cell_y)) else {
+                    continue;
+                };
+
+                found.extend(
+                    cell.iter()
+                        .filter(|(point, _)| (*point - center).len_pow2() <= radius_pow2)
+                        .map(|(point, value)| (*point, value)),
+                );
+            }
+        }
+
+        found
+    }
+
+    /// Removes every stored point
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.len = 0;
+    }
+}
+
+/// A quadtree of 2d points each carrying a value
+///
+/// Every node covers an [`Aabb2`]. Once a node holds more than `capacity` points it's split into four equal
+/// quadrants, unless it's already `max_depth` levels deep in which case it keeps growing instead.
+#[derive(Debug, Clone)]
+pub struct QuadTree<T> {
+    root: QuadNode<T>,
+    capacity: usize,
+    max_depth: u32,
+    len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct QuadNode<T> {
+    bounds: Aabb2,
+    depth: u32,
+    items: Vec<(Vec2, T)>,
+    children: Option<Box<[QuadNode<T>; 4]>>,
+}
+
+impl<T> QuadTree<T> {
+    /// Creates an empty quadtree covering `bounds`
+    ///
+    /// # Panics
+    /// When `capacity` is zero
+    #[must_use]
+    pub fn new(bounds: Aabb2, capacity: usize, max_depth: u32) -> Self {
+        assert!(capacity > 0, "Quadtree node capacity has to be positive");
+
+        Self {
+            root: QuadNode::new(bounds, 0),
+            capacity,
+            max_depth,
+            len: 0,
+        }
+    }
+
+    /// Returns the area covered by the tree
+    #[must_use]
+    pub fn bounds(&self) -> Aabb2 {
+        self.root.bounds
+    }
+
+    /// Returns the number of stored points
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether no points are stored
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Stores `value` at `point`
+    ///
+    /// Returns `false` and drops `value` if `point` lies outside of the bounds of the tree
+    pub fn insert(&mut self, point: Vec2, value: T) -> bool {
+        if !self.root.bounds.contains(point) {
+            return false;
+        }
+
+        self.root
+            .insert(point, value, self.capacity, self.max_depth);
+        self.len += 1;
+
+        true
+    }
+
+    /// Returns every stored point lying inside of `range` together with its value
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::spatial::QuadTree;
+    /// # use skala_engine_numerics::{Aabb2, Vec2};
+    /// let mut tree = QuadTree::new(Aabb2::new(Vec2::ZERO, Vec2::new(16.into(), 16.into())), 4, 8);
+    /// tree.insert(Vec2::new(1.into(), 1.into()), "inside");
+    /// tree.insert(Vec2::new(9.into(), 9.into()), "outside");
+    ///
+    /// let found = tree.query_range(Aabb2::new(Vec2::ZERO, Vec2::new(4.into(), 4.into())));
+    ///
+    /// assert_eq!(found, vec![(Vec2::new(1.into(), 1.into()), &"inside")]);
+    /// ```
+    #[must_use]
+    pub fn query_range(&self, range: Aabb2) -> Vec<(Vec2, &T)> {
+        let mut found = Vec::new();
+        self.root.query(range, &mut found);
+
+        found
+    }
+}
+
+impl<T> QuadNode<T> {
+    fn new(bounds: Aabb2, depth: u32) -> Self {
+        Self {
+            bounds,
+            depth,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn insert(&mut self, point: Vec2, value: T, capacity: usize, max_depth: u32) {
+        if let Some(children) = &mut self.children {
+            if let Some(child) = children
+                .iter_mut()
+                .find(|child| child.bounds.contains(point))
+            {
+                child.insert(point, value, capacity, max_depth);
+                return;
+            }
+        }
+
+        self.items.push((point, value));
+
+        if self.children.is_none() && self.items.len() > capacity && self.depth < max_depth {
+            self.split(capacity, max_depth);
+        }
+    }
+
+    fn split(&mut self, capacity: usize, max_depth: u32) {
+        let Aabb2 { min, max } = self.bounds;
+        let center = min.midpoint(max);
+        let depth = self.depth + 1;
+
+        let mut children = Box::new([
+            QuadNode::new(Aabb2::new(min, center), depth),
+            QuadNode::new(
+                Aabb2::new(Vec2::new(center.x, min.y), Vec2::new(max.x, center.y)),
+                depth,
+            ),
+            QuadNode::new(
+                Aabb2::new(Vec2::new(min.x, center.y), Vec2::new(center.x, max.y)),
+                depth,
+            ),
+            QuadNode::new(Aabb2::new(center, max), depth),
+        ]);
+
+        for (point, value) in std::mem::take(&mut self.items) {
+            let child = children
+                .iter_mut()
+                .find(|child| child.bounds.contains(point))
+                .expect("Quadrants cover the whole parent node");
+
+            child.insert(point, value, capacity, max_depth);
+        }
+
+        self.children = Some(children);
+    }
+
+    fn query<'a>(&'a self, range: Aabb2, found: &mut Vec<(Vec2, &'a T)>) {
+        if !self.bounds.intersects(range) {
+            return;
+        }
+
+        found.extend(
+            self.items
+                .iter()
+                .filter(|(point, _)| range.contains(*point))
+                .map(|(point, value)| (*point, value)),
+        );
+
+        if let Some(children) = &self.children {
+            for child in children.iter() {
+                child.query(range, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::aabb::Aabb2;
+    use crate::spatial::{grid_cell, grid_points_2d, grid_points_3d, QuadTree, SpatialHash2};
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn cells() {
+        let half = SignedFractional::from_num(0.5);
+
+        assert_eq!(grid_cell(Vec2::new(half, half), 1.into()), (0, 0));
+        assert_eq!(grid_cell(Vec2::new(-half, 3.into()), 1.into()), (-1, 3));
+        assert_eq!(grid_cell(Vec2::new(7.into(), 8.into()), 4.into()), (1, 2));
+    }
+
+    #[test]
+    fn radius_query() {
+        let mut hash = SpatialHash2::new(2.into());
+        hash.insert(Vec2::new(0.into(), 0.into()), 0);
+        hash.insert(Vec2::new(3.into(), 0.into()), 1);
+        hash.insert(Vec2::new((-2).into(), 2.into()), 2);
+        hash.insert(Vec2::new(3.into(), 3.into()), 3);
+        hash.insert(Vec2::new(10.into(), 10.into()), 4);
+
+        let mut found: Vec<i32> = hash
+            .query_radius(Vec2::new(0.into(), 0.into()), 3.into())
+            .into_iter()
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort_unstable();
+
+        assert_eq!(hash.len(), 5);
+        assert_eq!(found, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn clearing() {
+        let mut hash = SpatialHash2::new(2.into());
+        hash.insert(Vec2::new(1.into(), 1.into()), ());
+
+        hash.clear();
+
+        assert!(hash.is_empty());
+        assert!(hash.query_radius(Vec2::ZERO, 5.into()).is_empty());
+    }
+
+    #[test]
+    fn quadtree_range_query() {
+        let mut tree = QuadTree::new(
+            Aabb2::new(Vec2::ZERO, Vec2::new(64.into(), 64.into())),
+            2,
+            6,
+        );
+
+        for i in 0..8 {
+            assert!(tree.insert(Vec2::new(i.into(), i.into()), i));
+        }
+        assert!(tree.insert(Vec2::new(40.into(), 40.into()), 40));
+        assert!(tree.insert(Vec2::new(60.into(), 2.into()), 60));
+        assert!(!tree.insert(Vec2::new(70.into(), 2.into()), 70));
+
+        let mut found: Vec<i32> = tree
+            .query_range(Aabb2::new(
+                Vec2::new(2.into(), 2.into()),
+                Vec2::new(5.into(), 5.into()),
+            ))
+            .into_iter()
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort_unstable();
+
+        assert_eq!(tree.len(), 10);
+        assert_eq!(found, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn quadtree_depth_limit() {
+        let mut tree = QuadTree::new(Aabb2::new(Vec2::ZERO, Vec2::new(8.into(), 8.into())), 1, 2);
+
+        for i in 0..5 {
+            tree.insert(Vec2::ONE, i);
+        }
+
+        let found = tree.query_range(Aabb2::new(Vec2::ZERO, Vec2::ONE));
+
+        assert_eq!(found.len(), 5);
+    }
+
+    #[test]
+    fn grid_2d_corners() {
+        let origin = Vec2::new((-1).into(), 2.into());
+        let step = Vec2::new(SignedFractional::from_num(0.5), 3.into());
+
+        let points = grid_points_2d(origin, step, 4, 3);
+
+        assert_eq!(points.len(), 12);
+        assert_eq!(points[0], origin);
+        assert_eq!(
+            points[3],
+            Vec2::new(SignedFractional::from_num(0.5), 2.into())
+        );
+        assert_eq!(points[8], Vec2::new((-1).into(), 8.into()));
+        assert_eq!(
+            points[11],
+            Vec2::new(SignedFractional::from_num(0.5), 8.into())
+        );
+        assert!(grid_points_2d(origin, step, 0, 3).is_empty());
+    }
+
+    #[test]
+    fn grid_3d_corners() {
+        let origin = Vec3::new(1, 1, 1);
+        let step = Vec3::new(1, 2, -1);
+
+        let points = grid_points_3d(origin, step, 3, 2, 4);
+
+        assert_eq!(points.len(), 24);
+        assert_eq!(points[0], origin);
+        assert_eq!(points[2], Vec3::new(3, 1, 1));
+        assert_eq!(points[5], Vec3::new(3, 3, 1));
+        assert_eq!(points[18], Vec3::new(1, 1, -2));
+        assert_eq!(points[23], Vec3::new(3, 3, -2));
+    }
+}
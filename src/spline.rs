@@ -0,0 +1,188 @@
+use crate::vector::Vec2;
+use crate::SignedFractional;
+use fixed_sqrt::FixedSqrt;
+
+/// Evaluates a uniform Catmull-Rom spline segment between `p1` and `p2` at `t` from `[0, 1]`
+///
+/// `p0` and `p3` are the neighbouring control points shaping the tangents at the ends of the segment.
+/// Same as [`catmull_rom_alpha`] with an `alpha` of zero.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::spline::catmull_rom;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let points = [
+///     Vec2::new(0.into(), 0.into()),
+///     Vec2::new(1.into(), 0.into()),
+///     Vec2::new(2.into(), 0.into()),
+///     Vec2::new(3.into(), 0.into()),
+/// ];
+///
+/// let middle = catmull_rom(points[0], points[1], points[2], points[3], SignedFractional::from_num(0.5));
+///
+/// assert_eq!(middle, Vec2::new(SignedFractional::from_num(1.5), 0.into()));
+/// ```
+#[must_use]
+pub fn catmull_rom(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: SignedFractional) -> Vec2 {
+    catmull_rom_alpha(p0, p1, p2, p3, t, SignedFractional::ZERO)
+}
+
+/// Evaluates a Catmull-Rom spline segment between `p1` and `p2` at `t` from `[0, 1]` with knots spaced by the
+/// distance between control points raised to `alpha`
+///
+/// An `alpha` of zero gives the uniform spline, `0.5` the centripetal spline and one the chordal spline.
+/// The centripetal spline never forms cusps or loops within a segment, which the uniform spline does when control
+/// points are unevenly spaced.
+///
+/// # Panics
+/// When `alpha` lies outside of `[0, 1]`
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::spline::catmull_rom_alpha;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let p0 = Vec2::new(0.into(), 0.into());
+/// let p1 = Vec2::new(10.into(), 0.into());
+/// let p2 = Vec2::new(11.into(), 0.into());
+/// let p3 = Vec2::new(11.into(), 10.into());
+/// let centripetal = SignedFractional::from_num(0.5);
+///
+/// assert_eq!(catmull_rom_alpha(p0, p1, p2, p3, 0.into(), centripetal), p1);
+/// assert_eq!(catmull_rom_alpha(p0, p1, p2, p3, 1.into(), centripetal), p2);
+/// ```
+#[must_use]
+pub fn catmull_rom_alpha(
+    p0: Vec2,
+    p1: Vec2,
+    p2: Vec2,
+    p3: Vec2,
+    t: SignedFractional,
+    alpha: SignedFractional,
+) -> Vec2 {
+    assert!(
+        SignedFractional::ZERO <= alpha && alpha <= SignedFractional::ONE,
+        "Catmull-Rom alpha has to lie within [0, 1]"
+    );
+
+    let t0 = SignedFractional::ZERO;
+    let t1 = t0 + knot_interval(p0, p1, alpha);
+    let t2 = t1 + knot_interval(p1, p2, alpha);
+    let t3 = t2 + knot_interval(p2, p3, alpha);
+
+    let t = t1 + (t2 - t1) * t;
+    let along = |from: SignedFractional, to: SignedFractional| (t - from) / (to - from);
+
+    // Barry and Goldman's pyramidal formulation
+    let a1 = p0.lerp(p1, along(t0, t1));
+    let a2 = p1.lerp(p2, along(t1, t2));
+    let a3 = p2.lerp(p3, along(t2, t3));
+
+    let b1 = a1.lerp(a2, along(t0, t2));
+    let b2 = a2.lerp(a3, along(t1, t3));
+
+    b1.lerp(b2, along(t1, t2))
+}
+
+/// Distance between two consecutive knots of a Catmull-Rom spline
+fn knot_interval(from: Vec2, to: Vec2, alpha: SignedFractional) -> SignedFractional {
+    let interval = pow_unit((to - from).len(), alpha);
+
+    // Coincident control points would make the knots coincide and divide by zero
+    if interval == SignedFractional::ZERO {
+        SignedFractional::ONE
+    } else {
+        interval
+    }
+}
+
+/// Raises a non negative `base` to an `exponent` from `[0, 1]` by multiplying together repeated square roots of
+/// `base` matching the binary digits of `exponent`
+fn pow_unit(base: SignedFractional, exponent: SignedFractional) -> SignedFractional {
+    if exponent == SignedFractional::ONE {
+        return base;
+    }
+
+    let two = SignedFractional::from_num(2);
+    let mut result = SignedFractional::ONE;
+    let mut root = base;
+    let mut digit = SignedFractional::ONE;
+    let mut remaining = exponent;
+
+    while remaining > SignedFractional::ZERO && digit > SignedFractional::DELTA {
+        root = root.sqrt();
+        digit /= two;
+
+        if remaining >= digit {
+            result *= root;
+            remaining -= digit;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use crate::spline::{catmull_rom, catmull_rom_alpha, pow_unit};
+    use crate::vector::Vec2;
+    use crate::SignedFractional;
+
+    #[test]
+    fn uniform_passes_through_control_points() {
+        let p0 = Vec2::new(0.into(), 0.into());
+        let p1 = Vec2::new(1.into(), 2.into());
+        let p2 = Vec2::new(3.into(), 3.into());
+        let p3 = Vec2::new(4.into(), 1.into());
+
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 0.into()), p1);
+        assert_eq!(catmull_rom(p0, p1, p2, p3, 1.into()), p2);
+    }
+
+    #[test]
+    fn fractional_powers() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let nine = SignedFractional::from_num(9);
+        let sixteen = SignedFractional::from_num(16);
+
+        assert_eq!(pow_unit(nine, 0.into()), 1);
+        assert_eq!(pow_unit(nine, 1.into()), 9);
+        assert_eq!(pow_unit(nine, SignedFractional::from_num(0.5)), 3);
+        assert!(
+            (pow_unit(sixteen, SignedFractional::from_num(0.75)) - SignedFractional::from_num(8))
+                .abs()
+                < epsilon
+        );
+        assert_eq!(pow_unit(0.into(), SignedFractional::from_num(0.5)), 0);
+    }
+
+    #[test]
+    fn centripetal_avoids_overshoot() {
+        // Two close control points between two far away ones make the uniform spline loop past p2
+        let p0 = Vec2::new(0.into(), 0.into());
+        let p1 = Vec2::new(10.into(), 0.into());
+        let p2 = Vec2::new(11.into(), 0.into());
+        let p3 = Vec2::new(11.into(), 10.into());
+        let centripetal = SignedFractional::from_num(0.5);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        let samples = |alpha: SignedFractional| -> Vec<Vec2> {
+            (0..=16)
+                .map(|i| {
+                    let t = SignedFractional::from_num(i) / SignedFractional::from_num(16);
+                    catmull_rom_alpha(p0, p1, p2, p3, t, alpha)
+                })
+                .collect()
+        };
+
+        let uniform = samples(SignedFractional::ZERO);
+        let centripetal = samples(centripetal);
+
+        assert!(uniform.iter().any(|point| point.x > p2.x + epsilon));
+        assert!(centripetal.iter().all(|point| point.x <= p2.x + epsilon));
+        assert!(centripetal
+            .windows(2)
+            .all(|pair| pair[0].x <= pair[1].x + epsilon));
+    }
+}
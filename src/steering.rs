@@ -0,0 +1,264 @@
+use crate::vector::Vec2;
+use crate::SignedFractional;
+
+/// Returns the velocity moving from `pos` straight towards `target` at `max_speed`
+///
+/// Returns a zero vector once `pos` reaches `target`.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::steering::seek;
+/// # use skala_engine_numerics::Vec2;
+/// let velocity = seek(Vec2::ZERO, Vec2::new(0.into(), 10.into()), 3.into());
+///
+/// assert_eq!(velocity, Vec2::new(0.into(), 3.into()));
+/// ```
+#[must_use]
+pub fn seek(pos: Vec2, target: Vec2, max_speed: SignedFractional) -> Vec2 {
+    (target - pos)
+        .try_get_normalized()
+        .map_or(Vec2::ZERO, |direction| direction * max_speed)
+}
+
+/// Returns the velocity moving from `pos` towards `target`, slowing down to stop exactly at `target`
+///
+/// Outside of `slowing_radius` this behaves like [`seek`], inside it the speed falls linearly with the
+/// distance to `target` and reaches zero at `target`.
+///
+/// # Panics
+/// When `slowing_radius` isn't positive
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::steering::arrive;
+/// # use skala_engine_numerics::Vec2;
+/// let target = Vec2::new(8.into(), 0.into());
+///
+/// assert_eq!(arrive(Vec2::ZERO, target, 4.into(), 5.into()), Vec2::new(4.into(), 0.into()));
+/// assert_eq!(arrive(Vec2::new(6.into(), 0.into()), target, 4.into(), 4.into()), Vec2::new(2.into(), 0.into()));
+/// ```
+#[must_use]
+pub fn arrive(
+    pos: Vec2,
+    target: Vec2,
+    max_speed: SignedFractional,
+    slowing_radius: SignedFractional,
+) -> Vec2 {
+    assert!(
+        slowing_radius > SignedFractional::ZERO,
+        "Slowing radius has to be positive"
+    );
+
+    let offset = target - pos;
+    let distance = offset.len();
+
+    if distance == SignedFractional::ZERO {
+        return Vec2::ZERO;
+    }
+
+    let speed = if distance < slowing_radius {
+        max_speed * (distance / slowing_radius)
+    } else {
+        max_speed
+    };
+
+    offset * (speed / distance)
+}
+
+/// Turns `current_dir` towards `desired_dir` by at most `max_turn` radians
+///
+/// Returns `desired_dir` when it's already within `max_turn` of `current_dir`, otherwise `current_dir` rotated by
+/// `max_turn` in whichever direction reaches `desired_dir` sooner. Calling this once per step gives vehicles a
+/// limited turning rate instead of letting them snap to a new heading.
+///
+/// Both directions are expected to be unit vectors and `max_turn` to be non negative.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::steering::limit_turn_rate;
+/// # use skala_engine_numerics::Vec2;
+/// let heading = limit_turn_rate(Vec2::X, Vec2::Y, 2.into());
+///
+/// assert_eq!(heading, Vec2::Y);
+/// ```
+#[must_use]
+pub fn limit_turn_rate(current_dir: Vec2, desired_dir: Vec2, max_turn: SignedFractional) -> Vec2 {
+    let angle = current_dir.angle_to(desired_dir);
+
+    if angle.abs() <= max_turn {
+        return desired_dir;
+    }
+
+    if angle > SignedFractional::ZERO {
+        current_dir.rotate(max_turn)
+    } else {
+        current_dir.rotate(-max_turn)
+    }
+}
+
+/// Constrains `dir` to a sector (a fan) of directions within `half_width` radians of `center_angle`
+///
+/// Directions already inside the sector are returned unchanged, directions outside of it are rotated onto the
+/// nearest edge of the sector keeping their length. `center_angle` is measured counterclockwise from the positive
+/// x axis and `half_width` is expected to be non negative. Zero vectors are returned unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::steering::clamp_angle_to_sector;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let epsilon = SignedFractional::from_num(0.000_1);
+///
+/// // Aim straight down while only being able to look within 45° of the positive x axis
+/// let aim = clamp_angle_to_sector(-Vec2::Y, 0.into(), SignedFractional::FRAC_PI_4);
+/// let edge = Vec2::from_angle(-SignedFractional::FRAC_PI_4);
+///
+/// assert!(aim.approx_eq(edge, epsilon));
+/// ```
+#[must_use]
+pub fn clamp_angle_to_sector(
+    dir: Vec2,
+    center_angle: SignedFractional,
+    half_width: SignedFractional,
+) -> Vec2 {
+    let center = Vec2::from_angle(center_angle);
+    let offset = center.angle_to(dir);
+
+    if dir == Vec2::ZERO || offset.abs() <= half_width {
+        return dir;
+    }
+
+    let edge = if offset > SignedFractional::ZERO {
+        center.rotate(half_width)
+    } else {
+        center.rotate(-half_width)
+    };
+
+    edge * dir.len()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::steering::{arrive, clamp_angle_to_sector, limit_turn_rate, seek};
+    use crate::vector::Vec2;
+    use crate::SignedFractional;
+
+    #[test]
+    fn turn_is_capped() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let max_turn = SignedFractional::FRAC_PI_6;
+        let expected = Vec2::new(
+            SignedFractional::from_num(0.866_025),
+            SignedFractional::from_num(0.5),
+        );
+
+        let heading = limit_turn_rate(Vec2::X, Vec2::Y, max_turn);
+
+        assert!(heading.approx_eq(expected, epsilon));
+    }
+
+    #[test]
+    fn turn_clockwise() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let max_turn = SignedFractional::FRAC_PI_2;
+
+        let slightly_below_x =
+            Vec2::new(1.into(), SignedFractional::from_num(-0.01)).get_normalized();
+
+        let heading = limit_turn_rate(Vec2::Y, slightly_below_x, max_turn);
+
+        assert!(heading.approx_eq(Vec2::X, epsilon));
+    }
+
+    #[test]
+    fn small_turn_reaches_target() {
+        let desired = Vec2::new(
+            SignedFractional::from_num(0.6),
+            SignedFractional::from_num(0.8),
+        );
+
+        assert_eq!(limit_turn_rate(Vec2::X, desired, 1.into()), desired);
+    }
+
+    #[test]
+    fn seek_points_at_target() {
+        let pos = Vec2::new(1.into(), 1.into());
+        let epsilon = SignedFractional::from_num(0.000_01);
+
+        assert!(seek(pos, Vec2::new(4.into(), 5.into()), 10.into())
+            .approx_eq(Vec2::new(6.into(), 8.into()), epsilon));
+        assert_eq!(
+            seek(pos, Vec2::new((-5).into(), 1.into()), 2.into()),
+            Vec2::new((-2).into(), 0.into())
+        );
+        assert_eq!(seek(pos, pos, 2.into()), Vec2::ZERO);
+    }
+
+    #[test]
+    fn arrive_slows_within_radius() {
+        let target = Vec2::new(0.into(), 20.into());
+        let max_speed = SignedFractional::from_num(6);
+        let radius = SignedFractional::from_num(8);
+        let epsilon = SignedFractional::from_num(0.000_01);
+
+        assert!(arrive(Vec2::ZERO, target, max_speed, radius)
+            .approx_eq(Vec2::new(0.into(), 6.into()), epsilon));
+        assert_eq!(
+            arrive(Vec2::new(0.into(), 16.into()), target, max_speed, radius),
+            Vec2::new(0.into(), 3.into())
+        );
+        assert_eq!(
+            arrive(Vec2::new(0.into(), 18.into()), target, max_speed, radius),
+            Vec2::new(0.into(), SignedFractional::from_num(1.5))
+        );
+        assert_eq!(arrive(target, target, max_speed, radius), Vec2::ZERO);
+    }
+
+    #[test]
+    fn sector_inside() {
+        let dir = Vec2::new(3.into(), 1.into());
+
+        assert_eq!(
+            clamp_angle_to_sector(dir, 0.into(), SignedFractional::FRAC_PI_4),
+            dir
+        );
+        assert_eq!(
+            clamp_angle_to_sector(Vec2::ZERO, 0.into(), SignedFractional::FRAC_PI_4),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn sector_outside() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let center = SignedFractional::FRAC_PI_2;
+        let half_width = SignedFractional::FRAC_PI_6;
+
+        // Left of the sector clamps to its counterclockwise edge, right of it to the clockwise edge
+        let left = clamp_angle_to_sector(Vec2::new((-2).into(), 0.into()), center, half_width);
+        let right = clamp_angle_to_sector(
+            Vec2::new(0.into(), (-2).into()).rotate(SignedFractional::from_num(0.1)),
+            center,
+            half_width,
+        );
+
+        let half_sqrt_3 = SignedFractional::from_num(0.866_025);
+        assert!(left.approx_eq(
+            Vec2::new(
+                -SignedFractional::ONE,
+                half_sqrt_3 * SignedFractional::from_num(2)
+            ),
+            epsilon
+        ));
+        assert!(right.approx_eq(
+            Vec2::new(
+                SignedFractional::ONE,
+                half_sqrt_3 * SignedFractional::from_num(2)
+            ),
+            epsilon
+        ));
+    }
+}
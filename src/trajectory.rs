@@ -0,0 +1,145 @@
+use crate::vector::Vec3;
+use crate::SignedFractional;
+
+/// Smooths a recorded trajectory by replacing every position with the average of its neighbourhood
+///
+/// Each output position is the mean of the positions at most `window` samples before and after it.
+/// Near the ends of the trajectory the neighbourhood is cut short instead of being padded, so the ends are
+/// averaged over fewer samples. A `window` of zero returns the positions unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trajectory::moving_average;
+/// # use skala_engine_numerics::vector::Vec3;
+/// let jittery = [Vec3::new(0, 1, 0), Vec3::new(1, -1, 0), Vec3::new(2, 3, 0)];
+///
+/// assert_eq!(moving_average(&jittery, 1)[1], Vec3::new(1, 1, 0));
+/// ```
+#[must_use]
+pub fn moving_average(positions: &[Vec3], window: usize) -> Vec<Vec3> {
+    (0..positions.len())
+        .map(|i| {
+            let start = i.saturating_sub(window);
+            let end = (i + window + 1).min(positions.len());
+            let neighbourhood = &positions[start..end];
+
+            let sum = neighbourhood
+                .iter()
+                .fold(Vec3::ZERO, |sum, position| sum + *position);
+
+            sum / SignedFractional::from_num(neighbourhood.len())
+        })
+        .collect()
+}
+
+/// Estimates the velocity at every sample of a trajectory recorded every `dt` seconds
+///
+/// Interior samples use central differences, the first and last sample fall back to forward and backward
+/// differences. Feeding the resulting velocities back in yields accelerations.
+/// A trajectory with a single sample has zero velocity.
+///
+/// # Panics
+/// When `dt` is zero
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trajectory::finite_difference;
+/// # use skala_engine_numerics::vector::Vec3;
+/// let positions = [Vec3::new(0, 0, 0), Vec3::new(2, 0, 0), Vec3::new(4, 0, 0)];
+///
+/// assert_eq!(finite_difference(&positions, 1.into()), vec![Vec3::new(2, 0, 0); 3]);
+/// ```
+#[must_use]
+pub fn finite_difference(positions: &[Vec3], dt: SignedFractional) -> Vec<Vec3> {
+    assert!(
+        dt != SignedFractional::ZERO,
+        "Sampling interval can't be zero"
+    );
+
+    let count = positions.len();
+
+    if count < 2 {
+        return vec![Vec3::ZERO; count];
+    }
+
+    (0..count)
+        .map(|i| {
+            let before = i.saturating_sub(1);
+            let after = (i + 1).min(count - 1);
+            let steps = SignedFractional::from_num(after - before);
+
+            (positions[after] - positions[before]) / (dt * steps)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::trajectory::{finite_difference, moving_average};
+    use crate::vector::Vec3;
+    use crate::SignedFractional;
+
+    #[test]
+    fn smoothing_noisy_line() {
+        let noisy: Vec<Vec3> = (0..10)
+            .map(|i| Vec3::new(i, if i % 2 == 0 { 1 } else { -1 }, 0))
+            .collect();
+
+        let smoothed = moving_average(&noisy, 1);
+
+        assert_eq!(smoothed.len(), noisy.len());
+        for (original, smooth) in noisy.iter().zip(&smoothed).skip(1).take(8) {
+            assert_eq!(smooth.x, original.x);
+            assert!(smooth.y.abs() < original.y.abs());
+        }
+    }
+
+    #[test]
+    fn window_edges() {
+        let line = [Vec3::new(0, 0, 0), Vec3::new(2, 0, 0), Vec3::new(4, 0, 0)];
+
+        let smoothed = moving_average(&line, 5);
+
+        assert_eq!(smoothed, vec![Vec3::new(2, 0, 0); 3]);
+    }
+
+    #[test]
+    fn zero_window() {
+        let line = [Vec3::new(0, 3, 0), Vec3::new(2, -1, 0)];
+
+        assert_eq!(moving_average(&line, 0), line.to_vec());
+        assert!(moving_average(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn constant_velocity() {
+        let dt = SignedFractional::from_num(0.5);
+        let velocity = Vec3::new(4, -2, 1);
+        let positions: Vec<Vec3> = (0..6)
+            .map(|i| Vec3::new(1, 1, 1) + velocity * (dt * SignedFractional::from_num(i)))
+            .collect();
+
+        assert_eq!(finite_difference(&positions, dt), vec![velocity; 6]);
+    }
+
+    #[test]
+    fn acceleration() {
+        let positions: Vec<Vec3> = (0..5).map(|i| Vec3::new(i * i, 0, 0)).collect();
+
+        let velocities = finite_difference(&positions, 1.into());
+        let accelerations = finite_difference(&velocities, 1.into());
+
+        assert_eq!(accelerations[2], Vec3::new(2, 0, 0));
+    }
+
+    #[test]
+    fn short_trajectories() {
+        assert!(finite_difference(&[], 1.into()).is_empty());
+        assert_eq!(
+            finite_difference(&[Vec3::new(1, 2, 3)], 1.into()),
+            vec![Vec3::ZERO]
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use crate::vector::Vec2;
+use crate::{trig, SignedFractional};
+
+/// A 2d transform made of a scale, followed by a counterclockwise rotation, followed by a translation, meant for
+/// placing nodes of a scene graph
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Transform2D {
+    /// The offset added after scaling and rotating
+    pub translation: Vec2,
+    /// The counterclockwise rotation in radians
+    pub rotation: SignedFractional,
+    /// The scale applied to each axis before rotating
+    pub scale: Vec2,
+}
+
+impl Transform2D {
+    /// The transform leaving every point unchanged
+    pub const IDENTITY: Self = Self {
+        translation: Vec2::ZERO,
+        rotation: SignedFractional::ZERO,
+        scale: Vec2::ONE,
+    };
+
+    /// Creates a new transform from its parts
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::transform::Transform2D;
+    /// # use skala_engine_numerics::Vec2;
+    /// let transform = Transform2D::new(Vec2::ONE, 0.into(), Vec2::new(2.into(), 3.into()));
+    ///
+    /// assert_eq!(transform.transform_point(Vec2::ONE), Vec2::new(3.into(), 4.into()));
+    /// ```
+    #[must_use]
+    pub const fn new(translation: Vec2, rotation: SignedFractional, scale: Vec2) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Applies the transform to the point `p`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::transform::Transform2D;
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let transform = Transform2D::new(Vec2::new(5.into(), 0.into()), SignedFractional::FRAC_PI_2, Vec2::ONE);
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let moved = transform.transform_point(Vec2::X);
+    ///
+    /// assert!(moved.approx_eq(Vec2::new(5.into(), 1.into()), epsilon));
+    /// ```
+    #[must_use]
+    pub fn transform_point(&self, p: Vec2) -> Vec2 {
+        self.transform_vector(p) + self.translation
+    }
+
+    /// Applies the scale and rotation of the transform to the direction `v`, ignoring the translation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::transform::Transform2D;
+    /// # use skala_engine_numerics::Vec2;
+    /// let transform = Transform2D::new(Vec2::new(5.into(), 0.into()), 0.into(), Vec2::new(2.into(), 2.into()));
+    ///
+    /// assert_eq!(transform.transform_vector(Vec2::Y), Vec2::new(0.into(), 2.into()));
+    /// ```
+    #[must_use]
+    pub fn transform_vector(&self, v: Vec2) -> Vec2 {
+        Vec2::new(v.x * self.scale.x, v.y * self.scale.y).rotate(self.rotation)
+    }
+
+    /// Returns the transform applying `self` first and `other` second
+    ///
+    /// Rotations add up and scales multiply per axis. The result is exact when `other` scales both axes equally, a
+    /// non uniform scale applied after a rotation shears the result which a [`Transform2D`] can't represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::transform::Transform2D;
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let local = Transform2D::new(Vec2::X, 0.into(), Vec2::ONE);
+    /// let parent = Transform2D::new(Vec2::ZERO, SignedFractional::PI, Vec2::new(2.into(), 2.into()));
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let combined = local.then(&parent);
+    ///
+    /// assert!(combined.transform_point(Vec2::ZERO).approx_eq(Vec2::new((-2).into(), 0.into()), epsilon));
+    /// ```
+    #[must_use]
+    pub fn then(&self, other: &Self) -> Self {
+        Self {
+            translation: other.transform_point(self.translation),
+            rotation: trig::wrap_angle(self.rotation + other.rotation),
+            scale: Vec2::new(self.scale.x * other.scale.x, self.scale.y * other.scale.y),
+        }
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::transform::Transform2D;
+    use crate::vector::Vec2;
+    use crate::SignedFractional;
+
+    #[test]
+    fn identity() {
+        let p = Vec2::new(3.into(), (-7).into());
+
+        assert_eq!(Transform2D::IDENTITY.transform_point(p), p);
+        assert_eq!(Transform2D::IDENTITY.transform_vector(p), p);
+        assert_eq!(Transform2D::default(), Transform2D::IDENTITY);
+
+        let transform = Transform2D::new(Vec2::ONE, 1.into(), Vec2::new(2.into(), 3.into()));
+        assert_eq!(transform.then(&Transform2D::IDENTITY), transform);
+        assert_eq!(Transform2D::IDENTITY.then(&transform), transform);
+    }
+
+    #[test]
+    fn rotation_then_translation() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let rotation = Transform2D::new(Vec2::ZERO, SignedFractional::FRAC_PI_2, Vec2::ONE);
+        let translation = Transform2D::new(Vec2::new(10.into(), 5.into()), 0.into(), Vec2::ONE);
+        let p = Vec2::new(3.into(), 1.into());
+
+        // Rotating (3, 1) by 90° gives (-1, 3), translating it then gives (9, 8)
+        let expected = Vec2::new(9.into(), 8.into());
+        let combined = rotation.then(&translation);
+
+        assert!(combined.transform_point(p).approx_eq(expected, epsilon));
+        assert!(translation
+            .transform_point(rotation.transform_point(p))
+            .approx_eq(expected, epsilon));
+
+        // The other order rotates the translation too
+        let reversed = translation.then(&rotation);
+        assert!(reversed
+            .transform_point(p)
+            .approx_eq(Vec2::new((-6).into(), 13.into()), epsilon));
+
+        // Directions ignore the translation
+        assert!(combined
+            .transform_vector(Vec2::X)
+            .approx_eq(Vec2::Y, epsilon));
+    }
+
+    #[test]
+    fn composition_matches_applying_in_sequence() {
+        let epsilon = SignedFractional::from_num(0.001);
+        let local = Transform2D::new(
+            Vec2::new(2.into(), (-1).into()),
+            SignedFractional::FRAC_PI_6,
+            Vec2::new(3.into(), SignedFractional::from_num(0.5)),
+        );
+        let parent = Transform2D::new(
+            Vec2::new((-4).into(), 7.into()),
+            SignedFractional::FRAC_PI_3,
+            Vec2::new(2.into(), 2.into()),
+        );
+        let combined = local.then(&parent);
+
+        for p in [
+            Vec2::ZERO,
+            Vec2::ONE,
+            Vec2::new(5.into(), (-3).into()),
+            Vec2::new(SignedFractional::from_num(-1.5), 4.into()),
+        ] {
+            let expected = parent.transform_point(local.transform_point(p));
+
+            assert!(combined.transform_point(p).approx_eq(expected, epsilon));
+        }
+
+        assert!((combined.rotation - SignedFractional::FRAC_PI_2).abs() < epsilon);
+        assert_eq!(combined.scale, Vec2::new(6.into(), 1.into()));
+    }
+}
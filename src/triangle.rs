@@ -0,0 +1,572 @@
+use crate::vector::{Vec2, Vec3, Vector};
+use crate::SignedFractional;
+use std::ops::{Add, Mul};
+
+/// Returns the barycentric coordinates of `p` relative to the triangle `a`, `b`, `c`
+///
+/// The three weights sum to one and `p` equals `a * wa + b * wb + c * wc`. All weights are non negative exactly
+/// when `p` lies inside of the triangle. Returns `None` for degenerate triangles with zero area.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::triangle::barycentric;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let a = Vec2::new(0.into(), 0.into());
+/// let b = Vec2::new(4.into(), 0.into());
+/// let c = Vec2::new(0.into(), 4.into());
+/// let half = SignedFractional::from_num(0.5);
+///
+/// let weights = barycentric(Vec2::new(2.into(), 0.into()), a, b, c);
+///
+/// assert_eq!(weights, Some((half, half, 0.into())));
+/// ```
+#[must_use]
+pub fn barycentric(
+    p: Vec2,
+    a: Vec2,
+    b: Vec2,
+    c: Vec2,
+) -> Option<(SignedFractional, SignedFractional, SignedFractional)> {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+    let area = ab.cross(ac);
+
+    if area == SignedFractional::ZERO {
+        return None;
+    }
+
+    let wb = ap.cross(ac) / area;
+    let wc = ab.cross(ap) / area;
+
+    Some((SignedFractional::ONE - wb - wc, wb, wc))
+}
+
+/// Blends the attributes `a`, `b` and `c` of a triangle's vertices by the barycentric weights `bary`
+///
+/// Works for any attribute that can be scaled and added, like uv coordinates, normals or single values, and pairs
+/// with the weights returned by [`barycentric`].
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::triangle::interpolate_attribute;
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let half = SignedFractional::from_num(0.5);
+/// let weights = (half, half, 0.into());
+///
+/// let uv = interpolate_attribute(weights, Vec2::ZERO, Vec2::ONE, Vec2::X);
+/// let brightness = interpolate_attribute(weights, SignedFractional::from_num(2), 4.into(), 9.into());
+///
+/// assert_eq!(uv, Vec2::new(half, half));
+/// assert_eq!(brightness, 3);
+/// ```
+#[must_use]
+pub fn interpolate_attribute<T>(
+    bary: (SignedFractional, SignedFractional, SignedFractional),
+    a: T,
+    b: T,
+    c: T,
+) -> T
+where
+    T: Mul<SignedFractional, Output = T> + Add<Output = T>,
+{
+    a * bary.0 + b * bary.1 + c * bary.2
+}
+
+/// Returns the point of the triangle `a`, `b`, `c` closest to `p`
+///
+/// Finds which Voronoi region of the triangle `p` lies in, a vertex, an edge or the face, and projects `p` onto that
+/// feature. Degenerate triangles are handled as the segment or point they collapse to.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::triangle::closest_point_on_triangle;
+/// # use skala_engine_numerics::{SignedFractional, Vec3};
+/// let a = Vec3::new(0, 0, 0);
+/// let b = Vec3::new(4, 0, 0);
+/// let c = Vec3::new(0, 4, 0);
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// assert!(closest_point_on_triangle(Vec3::new(1, 1, 5), a, b, c).approx_eq(Vec3::new(1, 1, 0), epsilon));
+/// assert_eq!(closest_point_on_triangle(Vec3::new(-3, -2, 1), a, b, c), a);
+/// ```
+#[must_use]
+pub fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    // Work relative to `a` scaled to the size of the triangle, the products below grow with the fourth power of the
+    // size
+    let scale = a.chebyshev_distance(b).max(a.chebyshev_distance(c));
+
+    if scale == SignedFractional::ZERO {
+        return a;
+    }
+
+    let ab = (b - a) / scale;
+    let ac = (c - a) / scale;
+    let mut ap = (p - a) / scale;
+
+    // Only the part of `ap` within the plane of the triangle decides the closest point. Dropping the height keeps the
+    // products below precise for points far above or below the face
+    if let Some(normal) = ab.cross(ac).try_get_normalized() {
+        ap = ap - normal * ap.dot(normal);
+    }
+
+    // Points far away within the plane are outside of the triangle for sure, their products would overflow
+    if ap.chebyshev_distance(Vec3::ZERO) > SignedFractional::from_num(1024) {
+        return a + closest_offset_on_edges(ap, ab, ac) * scale;
+    }
+
+    let d1 = ab.dot(ap);
+    let d2 = ac.dot(ap);
+    if d1 <= SignedFractional::ZERO && d2 <= SignedFractional::ZERO {
+        return a;
+    }
+
+    let bp = ap - ab;
+    let d3 = ab.dot(bp);
+    let d4 = ac.dot(bp);
+    if d3 >= SignedFractional::ZERO && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    // A zero denominator means the edge collapsed to a point, which the vertex checks cover
+    if vc <= SignedFractional::ZERO
+        && d1 >= SignedFractional::ZERO
+        && d3 <= SignedFractional::ZERO
+        && d1 != d3
+    {
+        return a + (b - a) * (d1 / (d1 - d3));
+    }
+
+    let cp = ap - ac;
+    let d5 = ab.dot(cp);
+    let d6 = ac.dot(cp);
+    if d6 >= SignedFractional::ZERO && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= SignedFractional::ZERO
+        && d2 >= SignedFractional::ZERO
+        && d6 <= SignedFractional::ZERO
+        && d2 != d6
+    {
+        return a + (c - a) * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= SignedFractional::ZERO
+        && d4 - d3 >= SignedFractional::ZERO
+        && d5 - d6 >= SignedFractional::ZERO
+        && (d4 - d3) + (d5 - d6) != SignedFractional::ZERO
+    {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    // Inside of the face, the weights are the areas of the sub triangles
+    let total = va + vb + vc;
+
+    if total == SignedFractional::ZERO {
+        // The triangle is flat, its closest point is on one of its edges
+        return a + closest_offset_on_edges(ap, ab, ac) * scale;
+    }
+
+    a + (b - a) * (vb / total) + (c - a) * (vc / total)
+}
+
+/// Returns the offset from `a` of the point on the edges of a triangle closest to the point at the offset `ap`
+///
+/// The edges are given by the offsets `ab` and `ac` of the other two vertices from `a`.
+fn closest_offset_on_edges(ap: Vec3, ab: Vec3, ac: Vec3) -> Vec3 {
+    let bc = ac - ab;
+
+    [
+        ab * segment_parameter(ap, ab),
+        ac * segment_parameter(ap, ac),
+        ab + bc * segment_parameter(ap - ab, bc),
+    ]
+    .into_iter()
+    .min_by_key(|&closest| (ap - closest).len())
+    .unwrap_or(Vec3::ZERO)
+}
+
+/// Returns how far along `segment` the projection of `offset` lies, clamped to the segment
+///
+/// Both are relative to the start of the segment, a segment of zero length returns its start.
+fn segment_parameter(offset: Vec3, segment: Vec3) -> SignedFractional {
+    let len_pow2 = segment.len_pow2();
+
+    if len_pow2 == SignedFractional::ZERO {
+        return SignedFractional::ZERO;
+    }
+
+    (offset.dot(segment) / len_pow2).clamp(SignedFractional::ZERO, SignedFractional::ONE)
+}
+
+/// Returns `true` when the sphere of `radius` around `center` touches or overlaps the triangle `a`, `b`, `c`
+///
+/// Compares the squared distance from `center` to the [`closest_point_on_triangle`] with the squared radius, which
+/// makes it suitable for colliding characters against mesh geometry. Distances too large to square are compared
+/// directly instead.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::triangle::sphere_triangle_intersects;
+/// # use skala_engine_numerics::Vec3;
+/// let a = Vec3::new(0, 0, 0);
+/// let b = Vec3::new(4, 0, 0);
+/// let c = Vec3::new(0, 4, 0);
+///
+/// assert!(sphere_triangle_intersects(Vec3::new(1, 1, 1), 2.into(), a, b, c));
+/// assert!(!sphere_triangle_intersects(Vec3::new(1, 1, 3), 2.into(), a, b, c));
+/// ```
+#[must_use]
+pub fn sphere_triangle_intersects(
+    center: Vec3,
+    radius: SignedFractional,
+    a: Vec3,
+    b: Vec3,
+    c: Vec3,
+) -> bool {
+    let offset = Vector::from(closest_point_on_triangle(center, a, b, c) - center);
+
+    match (offset.checked_len_pow2(), radius.checked_mul(radius)) {
+        (Some(distance_pow2), Some(radius_pow2)) => distance_pow2 <= radius_pow2,
+        _ => offset.len() <= radius,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::triangle::{
+        barycentric, closest_point_on_triangle, interpolate_attribute, sphere_triangle_intersects,
+    };
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn centroid_weights() {
+        let a = Vec2::new(1.into(), 1.into());
+        let b = Vec2::new(7.into(), 2.into());
+        let c = Vec2::new(4.into(), 9.into());
+        let centroid = Vec2::new(4.into(), 4.into());
+        let third = SignedFractional::ONE / SignedFractional::from_num(3);
+        let epsilon = SignedFractional::from_num(0.000_001);
+
+        let (wa, wb, wc) = barycentric(centroid, a, b, c).unwrap();
+
+        assert!((wa - third).abs() < epsilon);
+        assert!((wb - third).abs() < epsilon);
+        assert!((wc - third).abs() < epsilon);
+        assert_eq!(wa + wb + wc, 1);
+    }
+
+    #[test]
+    fn vertex_weights() {
+        let a = Vec2::new(1.into(), 1.into());
+        let b = Vec2::new(7.into(), 2.into());
+        let c = Vec2::new(4.into(), 9.into());
+        let (zero, one) = (SignedFractional::ZERO, SignedFractional::ONE);
+
+        assert_eq!(barycentric(a, a, b, c), Some((one, zero, zero)));
+        assert_eq!(barycentric(b, a, b, c), Some((zero, one, zero)));
+        assert_eq!(barycentric(c, a, b, c), Some((zero, zero, one)));
+    }
+
+    #[test]
+    fn outside_and_degenerate() {
+        let a = Vec2::new(0.into(), 0.into());
+        let b = Vec2::new(2.into(), 0.into());
+        let c = Vec2::new(0.into(), 2.into());
+
+        let (wa, wb, wc) = barycentric(Vec2::new(3.into(), 3.into()), a, b, c).unwrap();
+        assert!(wa < SignedFractional::ZERO);
+        assert_eq!(a * wa + b * wb + c * wc, Vec2::new(3.into(), 3.into()));
+
+        let collinear = Vec2::new(4.into(), 0.into());
+        assert_eq!(barycentric(Vec2::ONE, a, b, collinear), None);
+        assert_eq!(barycentric(Vec2::ONE, a, a, a), None);
+    }
+
+    #[test]
+    fn attribute_interpolation() {
+        let a = Vec2::new(0.into(), 0.into());
+        let b = Vec2::new(4.into(), 0.into());
+        let c = Vec2::new(0.into(), 4.into());
+        let quarter = SignedFractional::from_num(0.25);
+
+        let weights = barycentric(Vec2::new(1.into(), 2.into()), a, b, c).unwrap();
+        assert_eq!(weights, (quarter, quarter, SignedFractional::from_num(0.5)));
+
+        let uv = interpolate_attribute(weights, Vec2::ZERO, Vec2::X, Vec2::ONE);
+        assert_eq!(
+            uv,
+            Vec2::new(
+                SignedFractional::from_num(0.75),
+                SignedFractional::from_num(0.5)
+            )
+        );
+
+        let height =
+            interpolate_attribute(weights, 8.into(), 4.into(), SignedFractional::from_num(-2));
+        assert_eq!(height, 2);
+
+        let normal = interpolate_attribute(weights, Vec3::X, Vec3::Y, Vec3::Z);
+        assert_eq!(
+            normal,
+            Vec3::new(quarter, quarter, SignedFractional::from_num(0.5))
+        );
+
+        // Weights of a vertex reproduce its attribute
+        let (zero, one) = (SignedFractional::ZERO, SignedFractional::ONE);
+        assert_eq!(
+            interpolate_attribute((zero, one, zero), Vec3::X, Vec3::Y, Vec3::Z),
+            Vec3::Y
+        );
+    }
+
+    #[test]
+    fn closest_point_regions() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(6, 0, 0);
+        let c = Vec3::new(0, 6, 0);
+        let epsilon = SignedFractional::from_num(0.000_001);
+        let closest = |p| closest_point_on_triangle(p, a, b, c);
+
+        // Above the face the point is projected straight down
+        assert!(closest(Vec3::new(1, 2, 7)).approx_eq(Vec3::new(1, 2, 0), epsilon));
+        assert!(closest(Vec3::new(2, 2, -3)).approx_eq(Vec3::new(2, 2, 0), epsilon));
+
+        // Nearest the edges
+        assert!(closest(Vec3::new(3, -4, 1)).approx_eq(Vec3::new(3, 0, 0), epsilon));
+        assert!(closest(Vec3::new(-2, 5, 0)).approx_eq(Vec3::new(0, 5, 0), epsilon));
+        assert!(closest(Vec3::new(5, 5, 2)).approx_eq(Vec3::new(3, 3, 0), epsilon));
+
+        // Nearest the vertices
+        assert_eq!(closest(Vec3::new(-1, -1, 4)), a);
+        assert_eq!(closest(Vec3::new(9, -1, 0)), b);
+        assert_eq!(closest(Vec3::new(-1, 9, -2)), c);
+
+        // Points on the triangle are their own closest point
+        assert_eq!(closest(b), b);
+        assert!(closest(Vec3::new(1, 1, 0)).approx_eq(Vec3::new(1, 1, 0), epsilon));
+    }
+
+    #[test]
+    fn closest_point_on_tilted_triangle() {
+        let a = Vec3::new(1, 0, 0);
+        let b = Vec3::new(0, 1, 0);
+        let c = Vec3::new(0, 0, 1);
+        let third = SignedFractional::ONE / SignedFractional::from_num(3);
+        let epsilon = SignedFractional::from_num(0.000_001);
+
+        let closest = closest_point_on_triangle(Vec3::new(2, 2, 2), a, b, c);
+        assert!(closest.approx_eq(Vec3::new(third, third, third), epsilon));
+
+        // Moving a point of the face along the normal doesn't change its closest point
+        let quarter = SignedFractional::from_num(0.25);
+        let on_face = Vec3::new(SignedFractional::from_num(0.5), quarter, quarter);
+        let closest = closest_point_on_triangle(on_face + Vec3::ONE, a, b, c);
+        assert!(closest.approx_eq(on_face, epsilon));
+
+        // Large triangles don't overflow
+        let closest = closest_point_on_triangle(
+            Vec3::new(10_000, 10_000, 5_000),
+            Vec3::ZERO,
+            Vec3::new(30_000, 0, 0),
+            Vec3::new(0, 30_000, 0),
+        );
+        assert!(closest.approx_eq(
+            Vec3::new(10_000, 10_000, 0),
+            SignedFractional::from_num(0.01)
+        ));
+    }
+
+    #[test]
+    fn sphere_intersection() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(6, 0, 0);
+        let c = Vec3::new(0, 6, 0);
+        let radius = SignedFractional::from_num(2);
+
+        // Straddling the face, the edges and a vertex
+        assert!(sphere_triangle_intersects(
+            Vec3::new(1, 1, 1),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(sphere_triangle_intersects(
+            Vec3::new(2, 2, -1),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(sphere_triangle_intersects(
+            Vec3::new(3, -1, 1),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(sphere_triangle_intersects(
+            Vec3::new(-1, -1, 1),
+            radius,
+            a,
+            b,
+            c
+        ));
+
+        // Touching counts as intersecting
+        assert!(sphere_triangle_intersects(
+            Vec3::new(7, 0, 0),
+            1.into(),
+            a,
+            b,
+            c
+        ));
+        assert!(sphere_triangle_intersects(
+            Vec3::new(1, 1, 2),
+            radius,
+            a,
+            b,
+            c
+        ));
+
+        // Clearly separated, including above the face and beyond the edges and vertices
+        assert!(!sphere_triangle_intersects(
+            Vec3::new(1, 1, 3),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(!sphere_triangle_intersects(
+            Vec3::new(5, 5, 0),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(!sphere_triangle_intersects(
+            Vec3::new(-2, -2, 0),
+            radius,
+            a,
+            b,
+            c
+        ));
+        assert!(!sphere_triangle_intersects(
+            Vec3::new(9, 0, 0),
+            radius,
+            a,
+            b,
+            c
+        ));
+    }
+
+    #[test]
+    fn closest_point_on_degenerate_triangles() {
+        let epsilon = SignedFractional::from_num(0.000_001);
+        let start = Vec3::new(0, 0, 0);
+        let end = Vec3::new(4, 0, 0);
+
+        // Every pair of collapsed vertices turns the triangle into the segment from `start` to `end`
+        for (a, b, c) in [
+            (start, start, end),
+            (start, end, start),
+            (end, start, start),
+            (start, end, end),
+        ] {
+            let closest = |p| closest_point_on_triangle(p, a, b, c);
+
+            assert!(closest(Vec3::new(2, 3, 0)).approx_eq(Vec3::new(2, 0, 0), epsilon));
+            assert!(closest(Vec3::new(1, -1, 5)).approx_eq(Vec3::new(1, 0, 0), epsilon));
+            assert_eq!(closest(Vec3::new(-1, 1, 0)), start);
+            assert_eq!(closest(Vec3::new(6, 0, 1)), end);
+        }
+
+        // Collinear vertices act as the segment spanning them
+        let middle = Vec3::new(1, 0, 0);
+        for (a, b, c) in [
+            (start, middle, end),
+            (middle, start, end),
+            (start, end, middle),
+        ] {
+            let closest = |p| closest_point_on_triangle(p, a, b, c);
+
+            assert!(closest(Vec3::new(3, 2, 0)).approx_eq(Vec3::new(3, 0, 0), epsilon));
+            assert!(closest(Vec3::new(2, 0, -2)).approx_eq(Vec3::new(2, 0, 0), epsilon));
+            assert_eq!(closest(Vec3::new(-2, 0, 0)), start);
+            assert_eq!(closest(Vec3::new(7, -1, 0)), end);
+        }
+
+        // All vertices in one place
+        let point = Vec3::new(1, 2, 3);
+        assert_eq!(
+            closest_point_on_triangle(Vec3::new(5, -2, 0), point, point, point),
+            point
+        );
+        assert_eq!(closest_point_on_triangle(point, point, point, point), point);
+    }
+
+    #[test]
+    fn far_away_sphere_intersection() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(6, 0, 0);
+        let c = Vec3::new(0, 6, 0);
+
+        // The squared distances don't fit into a SignedFractional
+        let far = Vec3::new(100_000, 0, 0);
+        assert!(!sphere_triangle_intersects(far, 2.into(), a, b, c));
+        assert!(!sphere_triangle_intersects(
+            Vec3::new(0, -60_000, 0),
+            SignedFractional::from_num(59_999),
+            a,
+            b,
+            c
+        ));
+        assert!(sphere_triangle_intersects(
+            far,
+            SignedFractional::from_num(99_994),
+            a,
+            b,
+            c
+        ));
+    }
+
+    #[test]
+    fn closest_point_far_from_small_triangle() {
+        let a = Vec3::new(0, 0, 0);
+        let b = Vec3::new(1, 0, 0);
+        let c = Vec3::new(0, 1, 0);
+        let quarter = SignedFractional::from_num(0.25);
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let closest = |p| closest_point_on_triangle(p, a, b, c);
+
+        // Far above the face and beyond an edge
+        assert!(closest(Vec3::new(quarter, quarter, 1000))
+            .approx_eq(Vec3::new(quarter, quarter, 0), epsilon));
+        assert!(closest(Vec3::new(quarter, quarter, -100_000))
+            .approx_eq(Vec3::new(quarter, quarter, 0), epsilon));
+        assert!(closest(Vec3::new(quarter, -3, 1000)).approx_eq(Vec3::new(quarter, 0, 0), epsilon));
+        assert!(closest(Vec3::new(2, 2, 5000))
+            .approx_eq(Vec3::new(quarter * 2, quarter * 2, 0), epsilon));
+
+        // Far away within the plane
+        assert!(
+            closest(Vec3::new(quarter, -100_000, 0)).approx_eq(Vec3::new(quarter, 0, 0), epsilon)
+        );
+        assert_eq!(closest(Vec3::new(-5000, -5000, 1000)), a);
+
+        // A sphere touching the face from far away
+        let center = Vec3::new(quarter, quarter, 1000);
+        assert!(sphere_triangle_intersects(center, 1000.into(), a, b, c));
+        assert!(!sphere_triangle_intersects(center, 999.into(), a, b, c));
+    }
+}
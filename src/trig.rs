@@ -0,0 +1,391 @@
+use crate::SignedFractional;
+
+/// Calculates the sine and cosine of `angle` in radians at once
+///
+/// Any angle is accepted, angles outside of `(-π, π]` are wrapped into that range first. Results are accurate to
+/// at least 20 fractional bits (an absolute error below `0.000_001`).
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::sin_cos;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// let (sin, cos) = sin_cos(SignedFractional::FRAC_PI_2);
+///
+/// assert!((sin - SignedFractional::ONE).abs() < epsilon);
+/// assert!(cos.abs() < epsilon);
+/// ```
+#[must_use]
+pub fn sin_cos(angle: SignedFractional) -> (SignedFractional, SignedFractional) {
+    let angle = wrap_angle(angle);
+
+    // Cordic only converges within a quarter turn of zero, fold the rest of the half turn onto that range
+    if angle > SignedFractional::FRAC_PI_2 {
+        let (sin, cos) = cordic::sin_cos(SignedFractional::PI - angle);
+        (sin, -cos)
+    } else if angle < -SignedFractional::FRAC_PI_2 {
+        let (sin, cos) = cordic::sin_cos(-SignedFractional::PI - angle);
+        (sin, -cos)
+    } else {
+        cordic::sin_cos(angle)
+    }
+}
+
+/// Calculates the sine of `angle` in radians
+///
+/// See [`sin_cos`] for accuracy.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::sin;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// assert!((sin(SignedFractional::FRAC_PI_6) - SignedFractional::from_num(0.5)).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn sin(angle: SignedFractional) -> SignedFractional {
+    sin_cos(angle).0
+}
+
+/// Calculates the cosine of `angle` in radians
+///
+/// See [`sin_cos`] for accuracy.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::cos;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// assert!((cos(SignedFractional::PI) + SignedFractional::ONE).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn cos(angle: SignedFractional) -> SignedFractional {
+    sin_cos(angle).1
+}
+
+/// Calculates the angle in radians between the positive x axis and the point `(x, y)`
+///
+/// The result lies in `(-π, π]` and is zero when both `x` and `y` are zero. Accurate to the same degree as
+/// [`sin_cos`].
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::atan2;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+///
+/// assert!((atan2(1.into(), (-1).into()) - SignedFractional::FRAC_PI_4 * SignedFractional::from_num(3)).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn atan2(y: SignedFractional, x: SignedFractional) -> SignedFractional {
+    if x == SignedFractional::ZERO && y == SignedFractional::ZERO {
+        return SignedFractional::ZERO;
+    }
+
+    // Keep the ratio passed to atan within [-1, 1] where it's most accurate
+    if x.abs() >= y.abs() {
+        let angle = cordic::atan(y / x);
+
+        if x > SignedFractional::ZERO {
+            angle
+        } else if y >= SignedFractional::ZERO {
+            angle + SignedFractional::PI
+        } else {
+            angle - SignedFractional::PI
+        }
+    } else {
+        let angle = cordic::atan(x / y);
+
+        if y > SignedFractional::ZERO {
+            SignedFractional::FRAC_PI_2 - angle
+        } else {
+            -SignedFractional::FRAC_PI_2 - angle
+        }
+    }
+}
+
+/// Wraps `angle` in radians into `(-π, π]` by adding or removing whole turns
+///
+/// Useful before comparing or subtracting angles, which would otherwise jump by a full turn around `±π`.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::wrap_angle;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+/// let three_halves_pi = SignedFractional::FRAC_PI_2 * SignedFractional::from_num(3);
+///
+/// assert!((wrap_angle(three_halves_pi) + SignedFractional::FRAC_PI_2).abs() < epsilon);
+/// assert_eq!(wrap_angle(-SignedFractional::PI), SignedFractional::PI);
+/// ```
+#[must_use]
+pub fn wrap_angle(angle: SignedFractional) -> SignedFractional {
+    // Doubling PI rather than using TAU keeps both ends of the range exactly one turn apart after rounding
+    let turn = SignedFractional::PI * SignedFractional::from_num(2);
+    let angle = angle % turn;
+
+    if angle > SignedFractional::PI {
+        angle - turn
+    } else if angle <= -SignedFractional::PI {
+        angle + turn
+    } else {
+        angle
+    }
+}
+
+/// Returns the signed shortest difference in radians from the angle `from` to the angle `to`
+///
+/// The result lies in `(-π, π]` and is positive when the shortest way from `from` to `to` is counterclockwise.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::angle_difference;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+/// let almost_half_turn = SignedFractional::from_num(3);
+///
+/// // Crossing from just below π to just above -π is a small counterclockwise step
+/// let difference = angle_difference(almost_half_turn, -almost_half_turn);
+///
+/// assert!((difference - (SignedFractional::TAU - SignedFractional::from_num(6))).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn angle_difference(from: SignedFractional, to: SignedFractional) -> SignedFractional {
+    wrap_angle(to - from)
+}
+
+/// Interpolates from the angle `from` to the angle `to` in radians along the shortest arc between them
+///
+/// `t` equal to zero returns `from` and one returns `to`, both wrapped into `(-π, π]` like the rest of the results.
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::trig::lerp_angle;
+/// # use skala_engine_numerics::SignedFractional;
+/// let epsilon = SignedFractional::from_num(0.000_001);
+/// let almost_half_turn = SignedFractional::from_num(3);
+///
+/// // Blending across π goes through π rather than through zero
+/// let halfway = lerp_angle(almost_half_turn, -almost_half_turn, SignedFractional::from_num(0.5));
+///
+/// assert!((halfway.abs() - SignedFractional::PI).abs() < epsilon);
+/// ```
+#[must_use]
+pub fn lerp_angle(
+    from: SignedFractional,
+    to: SignedFractional,
+    t: SignedFractional,
+) -> SignedFractional {
+    wrap_angle(from + angle_difference(from, to) * t)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::trig::{angle_difference, atan2, cos, lerp_angle, sin, sin_cos, wrap_angle};
+    use crate::SignedFractional;
+
+    fn epsilon() -> SignedFractional {
+        SignedFractional::from_num(0.000_001)
+    }
+
+    #[test]
+    fn known_values() {
+        let half_sqrt_2 = SignedFractional::SQRT_2 / SignedFractional::from_num(2);
+        let half_sqrt_3 = SignedFractional::from_num(0.866_025_403_784);
+        let half = SignedFractional::from_num(0.5);
+
+        let cases = [
+            (
+                SignedFractional::ZERO,
+                SignedFractional::ZERO,
+                SignedFractional::ONE,
+            ),
+            (SignedFractional::FRAC_PI_6, half, half_sqrt_3),
+            (SignedFractional::FRAC_PI_4, half_sqrt_2, half_sqrt_2),
+            (
+                SignedFractional::FRAC_PI_2,
+                SignedFractional::ONE,
+                SignedFractional::ZERO,
+            ),
+            (-SignedFractional::FRAC_PI_6, -half, half_sqrt_3),
+            (
+                -SignedFractional::FRAC_PI_2,
+                -SignedFractional::ONE,
+                SignedFractional::ZERO,
+            ),
+        ];
+
+        for (angle, expected_sin, expected_cos) in cases {
+            assert!(
+                (sin(angle) - expected_sin).abs() < epsilon(),
+                "sin({angle})"
+            );
+            assert!(
+                (cos(angle) - expected_cos).abs() < epsilon(),
+                "cos({angle})"
+            );
+        }
+    }
+
+    #[test]
+    fn beyond_quarter_turn() {
+        let half = SignedFractional::from_num(0.5);
+        let half_sqrt_3 = SignedFractional::from_num(0.866_025_403_784);
+        let five_sixths_pi = SignedFractional::PI - SignedFractional::FRAC_PI_6;
+
+        let (s, c) = sin_cos(five_sixths_pi);
+        assert!((s - half).abs() < epsilon());
+        assert!((c + half_sqrt_3).abs() < epsilon());
+
+        let (s, c) = sin_cos(-five_sixths_pi);
+        assert!((s + half).abs() < epsilon());
+        assert!((c + half_sqrt_3).abs() < epsilon());
+
+        assert!((cos(SignedFractional::PI) + SignedFractional::ONE).abs() < epsilon());
+    }
+
+    #[test]
+    fn range_reduction() {
+        let angles = [
+            SignedFractional::FRAC_PI_6,
+            SignedFractional::FRAC_PI_4 * SignedFractional::from_num(3),
+            -SignedFractional::FRAC_PI_3,
+        ];
+
+        for angle in angles {
+            for turns in [-3, -1, 1, 2, 5] {
+                let wrapped = angle + SignedFractional::TAU * SignedFractional::from_num(turns);
+                // Error in TAU grows with the number of turns added
+                let tolerance = epsilon() * SignedFractional::from_num(8);
+
+                assert!(
+                    (sin(wrapped) - sin(angle)).abs() < tolerance,
+                    "sin({wrapped})"
+                );
+                assert!(
+                    (cos(wrapped) - cos(angle)).abs() < tolerance,
+                    "cos({wrapped})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn atan2_quadrants() {
+        let one = SignedFractional::ONE;
+        let three_quarters_pi = SignedFractional::FRAC_PI_4 * SignedFractional::from_num(3);
+
+        let cases = [
+            (SignedFractional::ZERO, one, SignedFractional::ZERO),
+            (one, one, SignedFractional::FRAC_PI_4),
+            (one, SignedFractional::ZERO, SignedFractional::FRAC_PI_2),
+            (one, -one, three_quarters_pi),
+            (SignedFractional::ZERO, -one, SignedFractional::PI),
+            (-one, -one, -three_quarters_pi),
+            (-one, SignedFractional::ZERO, -SignedFractional::FRAC_PI_2),
+            (-one, one, -SignedFractional::FRAC_PI_4),
+            (
+                SignedFractional::ZERO,
+                SignedFractional::ZERO,
+                SignedFractional::ZERO,
+            ),
+        ];
+
+        for (y, x, expected) in cases {
+            assert!(
+                (atan2(y, x) - expected).abs() < epsilon(),
+                "atan2({y}, {x})"
+            );
+        }
+    }
+
+    #[test]
+    fn wrapping() {
+        let three = SignedFractional::from_num(3);
+
+        // Odd multiples of π land on π, the end of the range that is included
+        assert_eq!(
+            wrap_angle(SignedFractional::PI * three),
+            SignedFractional::PI
+        );
+        assert_eq!(
+            wrap_angle(-SignedFractional::PI * three),
+            SignedFractional::PI
+        );
+        assert_eq!(wrap_angle(SignedFractional::PI), SignedFractional::PI);
+        assert_eq!(wrap_angle(-SignedFractional::PI), SignedFractional::PI);
+        assert_eq!(wrap_angle(three), three);
+        assert_eq!(wrap_angle(-three), -three);
+        assert!(
+            (wrap_angle(SignedFractional::from_num(4))
+                - (SignedFractional::from_num(4) - SignedFractional::TAU))
+                .abs()
+                < epsilon()
+        );
+
+        let far =
+            SignedFractional::FRAC_PI_6 + SignedFractional::TAU * SignedFractional::from_num(-7);
+        assert!(
+            (wrap_angle(far) - SignedFractional::FRAC_PI_6).abs()
+                < epsilon() * SignedFractional::from_num(8)
+        );
+    }
+
+    #[test]
+    fn shortest_difference() {
+        let degrees = |degrees: i32| {
+            SignedFractional::PI * SignedFractional::from_num(degrees)
+                / SignedFractional::from_num(180)
+        };
+
+        let cases = [
+            (170, -170, 20),
+            (-170, 170, -20),
+            (10, 350, -20),
+            (30, 90, 60),
+            (90, 30, -60),
+            (45, 45, 0),
+            (0, 180, 180),
+            (0, -180, 180),
+        ];
+
+        for (from, to, expected) in cases {
+            let difference = angle_difference(degrees(from), degrees(to));
+
+            assert!(
+                (difference - degrees(expected)).abs() < epsilon(),
+                "angle_difference({from}°, {to}°) = {difference}"
+            );
+        }
+    }
+
+    #[test]
+    fn angle_interpolation_takes_short_arc() {
+        let degrees = |degrees: i32| {
+            SignedFractional::PI * SignedFractional::from_num(degrees)
+                / SignedFractional::from_num(180)
+        };
+        let from = degrees(350);
+        let to = degrees(10);
+
+        let halfway = lerp_angle(from, to, SignedFractional::from_num(0.5));
+        assert!(halfway.abs() < epsilon(), "Passes through 0° not 180°");
+
+        let quarter = lerp_angle(from, to, SignedFractional::from_num(0.25));
+        assert!((quarter - degrees(-5)).abs() < epsilon());
+
+        assert!((lerp_angle(from, to, 0.into()) - wrap_angle(from)).abs() < epsilon());
+        assert!((lerp_angle(from, to, 1.into()) - to).abs() < epsilon());
+        assert!((lerp_angle(to, from, SignedFractional::from_num(0.5))).abs() < epsilon());
+    }
+}
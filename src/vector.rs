@@ -1,5 +1,14 @@
+mod error;
+#[cfg(feature = "glam")]
+mod glam_interop;
+mod parse;
+#[cfg(feature = "rand")]
+mod random;
 mod vector2;
 mod vector3;
+mod vector_n;
 
+pub use error::*;
 pub use vector2::*;
 pub use vector3::*;
+pub use vector_n::*;
@@ -0,0 +1,63 @@
+use fixed::ParseFixedError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Reasons a vector can't be normalized
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+#[non_exhaustive]
+pub enum NormalizeError {
+    /// The vector has a magnitude of zero so it has no direction
+    ZeroVector,
+}
+
+impl Display for NormalizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizeError::ZeroVector => write!(f, "can't normalize a zero vector"),
+        }
+    }
+}
+
+impl Error for NormalizeError {}
+
+/// Reasons a string can't be parsed into a vector
+#[derive(Eq, PartialEq, Debug, Clone)]
+#[non_exhaustive]
+pub enum ParseVectorError {
+    /// The string contained a different number of comma separated components than the vector has
+    ComponentCount {
+        /// Number of components of the vector being parsed
+        expected: usize,
+        /// Number of components found in the string
+        found: usize,
+    },
+    /// One of the components isn't a valid number
+    InvalidComponent {
+        /// Zero based position of the offending component
+        index: usize,
+        /// Why the component couldn't be parsed
+        source: ParseFixedError,
+    },
+}
+
+impl Display for ParseVectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseVectorError::ComponentCount { expected, found } => {
+                write!(f, "expected {expected} vector components but found {found}")
+            }
+            ParseVectorError::InvalidComponent { index, .. } => {
+                write!(f, "vector component {index} isn't a valid number")
+            }
+        }
+    }
+}
+
+impl Error for ParseVectorError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseVectorError::ComponentCount { .. } => None,
+            ParseVectorError::InvalidComponent { source, .. } => Some(source),
+        }
+    }
+}
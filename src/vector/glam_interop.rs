@@ -0,0 +1,80 @@
+use crate::vector::{Vec2, Vec3};
+use crate::SignedFractional;
+
+/// Converts through `f32`, which keeps only 24 significant bits so most fractional values get rounded
+impl From<Vec2> for glam::Vec2 {
+    fn from(n: Vec2) -> Self {
+        glam::Vec2::new(n.x.to_num(), n.y.to_num())
+    }
+}
+
+/// # Panics
+/// If a component is NaN, infinite or too large to fit into a [`SignedFractional`]
+impl From<glam::Vec2> for Vec2 {
+    fn from(n: glam::Vec2) -> Self {
+        Vec2::new(
+            SignedFractional::from_num(n.x),
+            SignedFractional::from_num(n.y),
+        )
+    }
+}
+
+/// Converts through `f32`, which keeps only 24 significant bits so most fractional values get rounded
+impl From<Vec3> for glam::Vec3 {
+    fn from(n: Vec3) -> Self {
+        glam::Vec3::new(n.x.to_num(), n.y.to_num(), n.z.to_num())
+    }
+}
+
+/// # Panics
+/// If a component is NaN, infinite or too large to fit into a [`SignedFractional`]
+impl From<glam::Vec3> for Vec3 {
+    fn from(n: glam::Vec3) -> Self {
+        Vec3::new(
+            SignedFractional::from_num(n.x),
+            SignedFractional::from_num(n.y),
+            SignedFractional::from_num(n.z),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+
+    #[test]
+    fn round_trip_2d() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let x = Vec2::new(
+            SignedFractional::from_num(1.5),
+            SignedFractional::from_num(-2.375_123),
+        );
+
+        let converted: glam::Vec2 = x.into();
+        let back: Vec2 = converted.into();
+
+        assert!(converted.abs_diff_eq(glam::Vec2::new(1.5, -2.375_123), 0.000_1));
+        assert!(back.approx_eq(x, epsilon));
+        assert_eq!(
+            Vec2::from(glam::Vec2::new(0.25, -3.0)),
+            Vec2::new(SignedFractional::from_num(0.25), (-3).into())
+        );
+    }
+
+    #[test]
+    fn round_trip_3d() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let x = Vec3::new(
+            SignedFractional::from_num(0.1),
+            -40_000,
+            SignedFractional::from_num(2.543_1),
+        );
+
+        let converted: glam::Vec3 = x.into();
+        let back: Vec3 = converted.into();
+
+        assert!(converted.abs_diff_eq(glam::Vec3::new(0.1, -40_000.0, 2.543_1), 0.000_1));
+        assert!(back.approx_eq(x, epsilon));
+    }
+}
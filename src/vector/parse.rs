@@ -0,0 +1,37 @@
+use crate::vector::ParseVectorError;
+use crate::SignedFractional;
+
+/// Parses `N` comma separated numbers optionally wrapped in a single pair of parentheses
+///
+/// Whitespace around the parentheses and around every number is ignored.
+pub(crate) fn parse_components<const N: usize>(
+    s: &str,
+) -> Result<[SignedFractional; N], ParseVectorError> {
+    let trimmed = s.trim();
+    let inner = trimmed
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(trimmed)
+        .trim();
+
+    let found = if inner.is_empty() {
+        0
+    } else {
+        inner.split(',').count()
+    };
+
+    if found != N {
+        return Err(ParseVectorError::ComponentCount { expected: N, found });
+    }
+
+    let mut components = [SignedFractional::ZERO; N];
+
+    for (index, (component, token)) in components.iter_mut().zip(inner.split(',')).enumerate() {
+        *component = token
+            .trim()
+            .parse()
+            .map_err(|source| ParseVectorError::InvalidComponent { index, source })?;
+    }
+
+    Ok(components)
+}
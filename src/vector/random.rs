@@ -0,0 +1,186 @@
+use crate::vector::{Vec2, Vec3};
+use crate::SignedFractional;
+use fixed_sqrt::FixedSqrt;
+use rand::Rng;
+
+/// Picks a number uniformly from all the values representable between `min` and `max` inclusive
+fn random_fractional<R: Rng + ?Sized>(
+    min: SignedFractional,
+    max: SignedFractional,
+    rng: &mut R,
+) -> SignedFractional {
+    SignedFractional::from_bits(rng.gen_range(min.to_bits()..=max.to_bits()))
+}
+
+/// Picks an angle in radians uniformly from `[-π, π]`
+fn random_angle<R: Rng + ?Sized>(rng: &mut R) -> SignedFractional {
+    random_fractional(-SignedFractional::PI, SignedFractional::PI, rng)
+}
+
+impl Vec2 {
+    /// Creates a random unit vector with every direction equally likely
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    ///
+    /// let direction = Vec2::random_unit(&mut rng);
+    ///
+    /// assert!((direction.len() - SignedFractional::ONE).abs() < SignedFractional::from_num(0.000_1));
+    /// ```
+    #[must_use]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_angle(random_angle(rng))
+    }
+
+    /// Creates a vector with each component picked uniformly from between the matching components of `min` and `max`
+    /// inclusive
+    ///
+    /// # Panics
+    /// If any component of `min` is larger than the matching component of `max`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let (min, max) = (Vec2::ZERO, Vec2::new(4.into(), 2.into()));
+    ///
+    /// let point = Vec2::random_in_range(min, max, &mut rng);
+    ///
+    /// assert_eq!(point.clamp(min, max), point);
+    /// ```
+    #[must_use]
+    pub fn random_in_range<R: Rng + ?Sized>(min: Self, max: Self, rng: &mut R) -> Self {
+        Self {
+            x: random_fractional(min.x, max.x, rng),
+            y: random_fractional(min.y, max.y, rng),
+        }
+    }
+}
+
+impl Vec3 {
+    /// Creates a random unit [`Vec3`] with every direction equally likely
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    ///
+    /// let direction = Vec3::random_unit(&mut rng);
+    ///
+    /// assert!((direction.len() - SignedFractional::ONE).abs() < SignedFractional::from_num(0.000_1));
+    /// ```
+    #[must_use]
+    pub fn random_unit<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        // Archimedes' hat-box theorem, the height of uniformly distributed points on a sphere is itself uniform
+        let y = random_fractional(-SignedFractional::ONE, SignedFractional::ONE, rng);
+        let horizontal =
+            Vec2::from_angle(random_angle(rng)) * (SignedFractional::ONE - y * y).sqrt();
+
+        Self {
+            x: horizontal.x,
+            y,
+            z: horizontal.y,
+        }
+    }
+
+    /// Creates a [`Vec3`] with each component picked uniformly from between the matching components of `min` and
+    /// `max` inclusive
+    ///
+    /// # Panics
+    /// If any component of `min` is larger than the matching component of `max`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use rand::SeedableRng;
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let (min, max) = (Vec3::new(-1, 0, 2), Vec3::new(1, 4, 3));
+    ///
+    /// let point = Vec3::random_in_range(min, max, &mut rng);
+    ///
+    /// assert_eq!(point.clamp(min, max), point);
+    /// ```
+    #[must_use]
+    pub fn random_in_range<R: Rng + ?Sized>(min: Self, max: Self, rng: &mut R) -> Self {
+        Self {
+            x: random_fractional(min.x, max.x, rng),
+            y: random_fractional(min.y, max.y, rng),
+            z: random_fractional(min.z, max.z, rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{Vec2, Vec3};
+    use crate::SignedFractional;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn unit_vectors_have_unit_length() {
+        let mut rng = StdRng::seed_from_u64(0x5ca1a);
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        for _ in 0..256 {
+            assert!((Vec2::random_unit(&mut rng).len() - SignedFractional::ONE).abs() < epsilon);
+            assert!((Vec3::random_unit(&mut rng).len() - SignedFractional::ONE).abs() < epsilon);
+        }
+    }
+
+    #[test]
+    fn unit_vectors_cover_sphere_evenly() {
+        let mut rng = StdRng::seed_from_u64(0x5ca1a);
+        let samples: i32 = 4096;
+
+        // Every octant of the sphere should get roughly an eighth of the samples
+        let mut octants = [0; 8];
+
+        for _ in 0..samples {
+            let direction = Vec3::random_unit(&mut rng);
+            let octant = usize::from(direction.x > SignedFractional::ZERO)
+                + 2 * usize::from(direction.y > SignedFractional::ZERO)
+                + 4 * usize::from(direction.z > SignedFractional::ZERO);
+
+            octants[octant] += 1;
+        }
+
+        let expected = samples / 8;
+        assert!(octants
+            .iter()
+            .all(|count| (count - expected).abs() < expected / 5));
+
+        // The mean of uniformly distributed directions is close to the center of the sphere
+        let mean = (0..samples)
+            .map(|_| Vec3::random_unit(&mut rng))
+            .fold(Vec3::ZERO, |sum, direction| sum + direction)
+            / SignedFractional::from_num(samples);
+
+        assert!(mean.len() < SignedFractional::from_num(0.05));
+    }
+
+    #[test]
+    fn components_stay_in_range() {
+        let mut rng = StdRng::seed_from_u64(0x5ca1a);
+        let min = Vec3::new(-2, 0, 5);
+        let max = Vec3::new(1, 0, 6);
+
+        for _ in 0..256 {
+            let point = Vec3::random_in_range(min, max, &mut rng);
+
+            assert_eq!(point.clamp(min, max), point);
+            assert_eq!(point.y, 0);
+        }
+
+        let flat = Vec2::new(3.into(), (-1).into());
+        assert_eq!(Vec2::random_in_range(flat, flat, &mut rng), flat);
+    }
+}
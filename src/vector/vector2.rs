@@ -1,14 +1,22 @@
-use crate::SignedFractional;
+use crate::vector::{parse, NormalizeError, ParseVectorError, Vec3, Vector};
+use crate::{trig, SignedFractional};
+use fixed::traits::{Fixed, FixedSigned};
 use fixed_sqrt::FixedSqrt;
+use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
-#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
 /// A 2d vector
-pub struct Vec2 {
+///
+/// Backed by [`SignedFractional`] unless another signed fixed point type is picked with `T`, for example `I48F16`
+/// when more integer range is needed. Constants, constructors, [`Default`], tuple conversions and trigonometry are
+/// only provided for the default, which keeps call sites without type annotations inferring it.
+pub struct Vec2<T = SignedFractional> {
     #[allow(missing_docs)]
-    pub x: SignedFractional,
+    pub x: T,
     #[allow(missing_docs)]
-    pub y: SignedFractional,
+    pub y: T,
 }
 
 impl Vec2 {
@@ -18,6 +26,24 @@ impl Vec2 {
         y: SignedFractional::ZERO,
     };
 
+    /// A `vec2` with both it's coordinates set to one
+    pub const ONE: Self = Self {
+        x: SignedFractional::ONE,
+        y: SignedFractional::ONE,
+    };
+
+    /// A unit `vec2` pointing along the x axis
+    pub const X: Self = Self {
+        x: SignedFractional::ONE,
+        y: SignedFractional::ZERO,
+    };
+
+    /// A unit `vec2` pointing along the y axis
+    pub const Y: Self = Self {
+        x: SignedFractional::ZERO,
+        y: SignedFractional::ONE,
+    };
+
     /// Creates a new vector from given coordinates
     /// # Example
     ///
@@ -31,7 +57,9 @@ impl Vec2 {
     pub const fn new(x: SignedFractional, y: SignedFractional) -> Self {
         Self { x, y }
     }
+}
 
+impl<T: FixedSigned + FixedSqrt> Vec2<T> {
     /// Calculates the magnitude of a vector without squaring the result
     ///
     /// Useful when checking if vector is a [unit vector](https://en.wikipedia.org/wiki/Unit_vector) without wasting cpu cycles
@@ -46,14 +74,16 @@ impl Vec2 {
     /// assert_eq!(vector.len_pow2(), length);
     /// ```
     #[must_use]
-    pub fn len_pow2(&self) -> SignedFractional {
-        self.x * self.x + self.y * self.y
+    pub fn len_pow2(&self) -> T {
+        Vector::from(*self).len_pow2()
     }
 
     /// Calculates the magnitude of a vector
     ///
     /// If checking if a vector is a [unit vector](https://en.wikipedia.org/wiki/Unit_vector) prefer using `len_pow2`
     ///
+    /// Works even when `len_pow2` would overflow as long as the magnitude itself fits into the backing type
+    ///
     /// # Example
     ///
     /// ```
@@ -65,8 +95,62 @@ impl Vec2 {
     /// assert_eq!(vector.len_pow2(), length);
     /// ```
     #[must_use]
-    pub fn len(&self) -> SignedFractional {
-        self.len_pow2().sqrt()
+    pub fn len(&self) -> T {
+        Vector::from(*self).len()
+    }
+
+    /// Calculates the dot product of two vectors
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let a = Vec2::new(1.into(), 2.into());
+    /// let b = Vec2::new(3.into(), 4.into());
+    /// let dot: SignedFractional = 11.into();
+    ///
+    /// assert_eq!(a.dot(b), dot);
+    /// ```
+    #[must_use]
+    pub fn dot(&self, other: Self) -> T {
+        Vector::from(*self).dot(other.into())
+    }
+
+    /// Calculates the dot product of a vector and a tuple of coordinates without building a temporary vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let a = Vec2::new(1.into(), 2.into());
+    /// let dot: SignedFractional = 11.into();
+    ///
+    /// assert_eq!(a.dot_tuple((3.into(), 4.into())), dot);
+    /// ```
+    #[must_use]
+    pub fn dot_tuple(&self, other: (T, T)) -> T {
+        self.x * other.0 + self.y * other.1
+    }
+
+    /// Calculates the 2d cross product (also known as the perp dot product) of two vectors
+    ///
+    /// The result is positive when `other` lies counterclockwise from `self`, negative when it lies clockwise and zero
+    /// when the vectors are parallel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let a = Vec2::new(1.into(), 0.into());
+    /// let b = Vec2::new(0.into(), 1.into());
+    /// let one: SignedFractional = 1.into();
+    ///
+    /// assert_eq!(a.cross(b), one);
+    /// assert_eq!(b.cross(a), -one);
+    /// ```
+    #[must_use]
+    pub fn cross(&self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
     }
 
     /// Modifies vector to have magnitude 1
@@ -85,64 +169,725 @@ impl Vec2 {
     ///
     /// assert_eq!(vector, normalized);
     /// ```
-    pub fn normalize(&mut self) {
-        *self /= self.len();
+    pub fn normalize(&mut self) {
+        *self /= self.len();
+    }
+
+    /// Creates a new `vec2` with same direction as `self` but magnitude 1
+    ///
+    /// # Panics
+    /// When vector is a zero vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let mut vector = Vec2::new(4.into(), 0.into());
+    /// let normalized = Vec2::new(1.into(), 0.into());
+    ///
+    /// assert_eq!(vector.get_normalized(), normalized);
+    /// ```
+    #[must_use]
+    pub fn get_normalized(&self) -> Self {
+        Vector::from(*self).get_normalized().into()
+    }
+
+    /// Checks whether every component of `self` differs from the matching component of `other` by at most `epsilon`
+    ///
+    /// Useful when comparing results of calculations involving square roots, which are rarely exact
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let diagonal = Vec2::new(1.into(), 1.into()).get_normalized();
+    /// let expected = Vec2::new(SignedFractional::from_num(0.70710678), SignedFractional::from_num(0.70710678));
+    ///
+    /// assert!(diagonal.approx_eq(expected, SignedFractional::from_num(0.000_001)));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: Self, epsilon: T) -> bool {
+        Vector::from(*self).approx_eq(other.into(), epsilon)
+    }
+
+    /// Calculates the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between two vectors
+    ///
+    /// The sum of the absolute differences of their coordinates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let a = Vec2::new(1.into(), 1.into());
+    /// let b = Vec2::new(4.into(), (-3).into());
+    ///
+    /// assert_eq!(a.manhattan_distance(b), 7);
+    /// ```
+    #[must_use]
+    pub fn manhattan_distance(&self, other: Self) -> T {
+        (*self - other).abs().element_sum()
+    }
+
+    /// Calculates the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) between two vectors
+    ///
+    /// The largest absolute difference of their coordinates
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let a = Vec2::new(1.into(), 1.into());
+    /// let b = Vec2::new(4.into(), (-3).into());
+    ///
+    /// assert_eq!(a.chebyshev_distance(b), 4);
+    /// ```
+    #[must_use]
+    pub fn chebyshev_distance(&self, other: Self) -> T {
+        let difference = (*self - other).abs();
+
+        difference.x.max(difference.y)
+    }
+
+    /// Returns the sum of the components of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new(3.into(), 4.into());
+    ///
+    /// assert_eq!(vector.element_sum(), 7);
+    /// ```
+    #[must_use]
+    pub fn element_sum(&self) -> T {
+        self.x + self.y
+    }
+
+    /// Returns the product of the components of `self`
+    ///
+    /// # Panics
+    /// When the product doesn't fit into `T` and overflow checks are enabled
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new(3.into(), 4.into());
+    ///
+    /// assert_eq!(vector.element_product(), 12);
+    /// ```
+    #[must_use]
+    pub fn element_product(&self) -> T {
+        self.x * self.y
+    }
+
+    /// Returns a vector made by applying `f` to each component of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let vector = Vec2::new(SignedFractional::from_num(1.5), SignedFractional::from_num(-2.25));
+    ///
+    /// assert_eq!(vector.map(SignedFractional::floor), Vec2::new(1.into(), (-3).into()));
+    /// ```
+    #[must_use]
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        Vector::from(*self).map(f).into()
+    }
+
+    /// Returns a vector made of the absolute values of the components of `self`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new((-3).into(), 2.into());
+    ///
+    /// assert_eq!(vector.abs(), Vec2::new(3.into(), 2.into()));
+    /// ```
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+
+    /// Returns a vector made of the signs of the components of `self`
+    ///
+    /// Each component is `1` if positive, `-1` if negative and `0` if zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new((-3).into(), 0.into());
+    ///
+    /// assert_eq!(vector.signum(), Vec2::new((-1).into(), 0.into()));
+    /// ```
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Returns a vector made of the components of `self` rounded down to the nearest integer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let vector = Vec2::new(SignedFractional::from_num(1.5), SignedFractional::from_num(-1.5));
+    ///
+    /// assert_eq!(vector.floor(), Vec2::new(1.into(), (-2).into()));
+    /// ```
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+        }
+    }
+
+    /// Returns a vector made of the components of `self` rounded up to the nearest integer
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let vector = Vec2::new(SignedFractional::from_num(1.5), SignedFractional::from_num(-1.5));
+    ///
+    /// assert_eq!(vector.ceil(), Vec2::new(2.into(), (-1).into()));
+    /// ```
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+        }
+    }
+
+    /// Returns a vector made of the components of `self` rounded to the nearest integer
+    ///
+    /// Components halfway between two integers are rounded away from zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let vector = Vec2::new(SignedFractional::from_num(1.25), SignedFractional::from_num(-1.5));
+    ///
+    /// assert_eq!(vector.round(), Vec2::new(1.into(), (-2).into()));
+    /// ```
+    #[must_use]
+    pub fn round(&self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+        }
+    }
+
+    /// Creates a new `vec2` made of the reciprocals of the components of `self`
+    ///
+    /// Useful for turning a scale into its inverse
+    ///
+    /// # Panics
+    /// If any component is zero or so close to zero that its reciprocal overflows, see [`try_recip`](Self::try_recip)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let scale = Vec2::new(2.into(), (-4).into());
+    ///
+    /// assert_eq!(scale.recip(), Vec2::new(SignedFractional::from_num(0.5), SignedFractional::from_num(-0.25)));
+    /// ```
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        Self {
+            x: self.x.recip(),
+            y: self.y.recip(),
+        }
+    }
+
+    /// Creates a new `vec2` made of the reciprocals of the components of `self` or `None` if any of them can't be
+    /// represented
+    ///
+    /// Returns `None` when a component is zero or so close to zero that its reciprocal overflows
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let scale = Vec2::new(2.into(), (-4).into());
+    ///
+    /// assert_eq!(scale.try_recip(), Some(scale.recip()));
+    /// assert_eq!(Vec2::X.try_recip(), None);
+    /// ```
+    #[must_use]
+    pub fn try_recip(&self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_recip()?,
+            y: self.y.checked_recip()?,
+        })
+    }
+
+    /// Returns a vector made of the smaller of each pair of components
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let a = Vec2::new(1.into(), 5.into());
+    /// let b = Vec2::new(3.into(), 2.into());
+    ///
+    /// assert_eq!(a.min(b), Vec2::new(1.into(), 2.into()));
+    /// ```
+    #[must_use]
+    pub fn min(&self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// Returns a vector made of the larger of each pair of components
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let a = Vec2::new(1.into(), 5.into());
+    /// let b = Vec2::new(3.into(), 2.into());
+    ///
+    /// assert_eq!(a.max(b), Vec2::new(3.into(), 5.into()));
+    /// ```
+    #[must_use]
+    pub fn max(&self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    /// Restricts every component of `self` to lie between the matching components of `lo` and `hi`
+    ///
+    /// If a component of `lo` is greater than the matching component of `hi` the `hi` component wins
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let lo = Vec2::new(0.into(), 0.into());
+    /// let hi = Vec2::new(10.into(), 10.into());
+    ///
+    /// assert_eq!(Vec2::new(15.into(), 5.into()).clamp(lo, hi), Vec2::new(10.into(), 5.into()));
+    /// ```
+    #[must_use]
+    pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    /// Linearly interpolates between `self` and `other`
+    ///
+    /// `t` equal to zero returns `self` and `t` equal to one returns `other`, values outside of that range extrapolate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let start = Vec2::new(0.into(), 0.into());
+    /// let end = Vec2::new(4.into(), 8.into());
+    ///
+    /// assert_eq!(start.lerp(end, SignedFractional::from_num(0.25)), Vec2::new(1.into(), 2.into()));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Interpolates between the unit directions `self` and `other` and normalizes the result
+    ///
+    /// A cheap approximation of spherical interpolation, both inputs should be unit length. When the directions are
+    /// opposite and the interpolation passes through zero the closer of the two inputs is returned instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let blended = Vec2::X.nlerp(Vec2::Y, SignedFractional::from_num(0.5));
+    /// let expected = Vec2::ONE.get_normalized();
+    ///
+    /// assert!(blended.approx_eq(expected, SignedFractional::from_num(0.000_01)));
+    /// ```
+    #[must_use]
+    pub fn nlerp(&self, other: Self, t: T) -> Self {
+        self.lerp(other, t).try_get_normalized().unwrap_or_else(|| {
+            if t < T::from_num(0.5) {
+                *self
+            } else {
+                other
+            }
+        })
+    }
+
+    /// Interpolates between `self` and `other` easing in and out with the `3t² - 2t³` smoothstep curve
+    ///
+    /// `t` is clamped to `[0, 1]`, zero returns `self` and one returns `other`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let start = Vec2::new(0.into(), 0.into());
+    /// let end = Vec2::new(4.into(), 8.into());
+    ///
+    /// assert_eq!(start.smoothstep(end, SignedFractional::from_num(0.25)), Vec2::new(SignedFractional::from_num(0.625), SignedFractional::from_num(1.25)));
+    /// assert_eq!(start.smoothstep(end, 2.into()), end);
+    /// ```
+    #[must_use]
+    pub fn smoothstep(&self, other: Self, t: T) -> Self {
+        self.lerp(other, smoothstep_curve(t))
+    }
+
+    /// Returns the point halfway between `self` and `other`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let a = Vec2::new(0.into(), 0.into());
+    /// let b = Vec2::new(4.into(), 6.into());
+    ///
+    /// assert_eq!(a.midpoint(b), Vec2::new(2.into(), 3.into()));
+    /// ```
+    #[must_use]
+    pub fn midpoint(&self, other: Self) -> Self {
+        (*self + other) / T::from_num(2)
+    }
+
+    /// Creates a new `vec2` with same direction as `self` but magnitude 1
+    /// If `self` is a zero vector returns None otherwise returns created vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let mut vector = Vec2::new(4.into(), 0.into());
+    /// let normalized = Vec2::new(1.into(), 0.into());
+    ///
+    /// assert_eq!(vector.try_get_normalized(), Some(normalized));
+    /// assert_eq!(Vec2::ZERO.try_get_normalized(), None);
+    /// ```
+    #[must_use]
+    pub fn try_get_normalized(&self) -> Option<Self> {
+        Vector::from(*self).try_get_normalized().map(Self::from)
+    }
+
+    /// Creates a new `vec2` with same direction as `self` but magnitude 1
+    ///
+    /// # Errors
+    /// Returns [`NormalizeError::ZeroVector`] if `self` is a zero vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::vector::NormalizeError;
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new(4.into(), 0.into());
+    ///
+    /// assert_eq!(vector.try_normalize(), Ok(Vec2::new(1.into(), 0.into())));
+    /// assert_eq!(Vec2::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, NormalizeError> {
+        self.try_get_normalized().ok_or(NormalizeError::ZeroVector)
+    }
+
+    /// Creates a new `vec2` with same direction as `self` but magnitude 1 or a zero vector if `self` is a zero vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new(0.into(), 3.into());
+    ///
+    /// assert_eq!(vector.normalize_or_zero(), Vec2::new(0.into(), 1.into()));
+    /// assert_eq!(Vec2::ZERO.normalize_or_zero(), Vec2::ZERO);
+    /// ```
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.try_get_normalized().unwrap_or(Self {
+            x: T::ZERO,
+            y: T::ZERO,
+        })
+    }
+
+    /// Modifies vector to have magnitude 1, leaving zero vectors unchanged
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let mut vector = Vec2::new(0.into(), 3.into());
+    /// let mut zero = Vec2::ZERO;
+    ///
+    /// vector.normalize_or_zero_mut();
+    /// zero.normalize_or_zero_mut();
+    ///
+    /// assert_eq!(vector, Vec2::new(0.into(), 1.into()));
+    /// assert_eq!(zero, Vec2::ZERO);
+    /// ```
+    pub fn normalize_or_zero_mut(&mut self) {
+        *self = self.normalize_or_zero();
+    }
+
+    /// Creates a new `vec2` with same direction as `self` but magnitude at most `max_len`
+    ///
+    /// Vectors already shorter than `max_len` (including the zero vector) are returned unchanged
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let velocity = Vec2::new(6.into(), 8.into());
+    ///
+    /// assert_eq!(velocity.clamp_magnitude(5.into()), Vec2::new(3.into(), 4.into()));
+    /// assert_eq!(velocity.clamp_magnitude(20.into()), velocity);
+    /// ```
+    #[must_use]
+    pub fn clamp_magnitude(&self, max_len: T) -> Self {
+        let len = self.len();
+
+        if len <= max_len || len == T::ZERO {
+            return *self;
+        }
+
+        *self * (max_len / len)
+    }
+
+    /// Moves `self` towards `target` by at most `max_delta`
+    ///
+    /// Returns `target` if it's closer than `max_delta`, so the result never overshoots
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let position = Vec2::new(0.into(), 0.into());
+    /// let target = Vec2::new(8.into(), 0.into());
+    ///
+    /// assert_eq!(position.move_towards(target, 4.into()), Vec2::new(4.into(), 0.into()));
+    /// assert_eq!(position.move_towards(target, 15.into()), target);
+    /// ```
+    #[must_use]
+    pub fn move_towards(&self, target: Self, max_delta: T) -> Self {
+        let offset = target - *self;
+        let distance = offset.len();
+
+        if distance <= max_delta || distance == T::ZERO {
+            return target;
+        }
+
+        *self + offset * (max_delta / distance)
+    }
+
+    /// Creates a [`Vec3`] from a vector by adding `z` as the third coordinate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{Vec2, Vec3};
+    /// let vector = Vec2::new(1.into(), 2.into());
+    ///
+    /// assert_eq!(vector.extend(3.into()), Vec3::new(1, 2, 3));
+    /// ```
+    #[must_use]
+    pub fn extend(&self, z: T) -> Vec3<T> {
+        Vec3 {
+            x: self.x,
+            y: self.y,
+            z,
+        }
+    }
+}
+
+impl Vec2 {
+    /// Creates a unit vector pointing `radians` counterclockwise from the positive x axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let vector = Vec2::from_angle(SignedFractional::FRAC_PI_2);
+    ///
+    /// assert!(vector.approx_eq(Vec2::Y, epsilon));
+    /// ```
+    #[must_use]
+    pub fn from_angle(radians: SignedFractional) -> Self {
+        let (sin, cos) = trig::sin_cos(radians);
+
+        Self { x: cos, y: sin }
+    }
+
+    /// Returns the angle in radians between the positive x axis and a vector
+    ///
+    /// The result lies in `(-π, π]`, a zero vector has an angle of zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let vector = Vec2::new(0.into(), (-3).into());
+    ///
+    /// assert!((vector.to_angle() + SignedFractional::FRAC_PI_2).abs() < epsilon);
+    /// ```
+    #[must_use]
+    pub fn to_angle(&self) -> SignedFractional {
+        trig::atan2(self.y, self.x)
+    }
+
+    /// Creates a vector from polar coordinates, `radius` away from the origin at `angle` radians counterclockwise from
+    /// the positive x axis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let vector = Vec2::from_polar(2.into(), SignedFractional::PI);
+    ///
+    /// assert!(vector.approx_eq(Vec2::new((-2).into(), 0.into()), epsilon));
+    /// ```
+    #[must_use]
+    pub fn from_polar(radius: SignedFractional, angle: SignedFractional) -> Self {
+        Self::from_angle(angle) * radius
+    }
+
+    /// Returns the polar coordinates `(radius, angle)` of a vector
+    ///
+    /// The angle lies in `(-π, π]`, see [`to_angle`](Self::to_angle). A zero vector has both a radius and an angle
+    /// of zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let (radius, angle) = Vec2::new(0.into(), 3.into()).to_polar();
+    ///
+    /// assert_eq!(radius, 3);
+    /// assert!((angle - SignedFractional::FRAC_PI_2).abs() < epsilon);
+    /// ```
+    #[must_use]
+    pub fn to_polar(&self) -> (SignedFractional, SignedFractional) {
+        (self.len(), self.to_angle())
+    }
+
+    /// Returns the signed angle in radians needed to rotate `self` onto the direction of `other`
+    ///
+    /// The result lies in `(-π, π]` and is positive when the rotation is counterclockwise. The angle to or from a zero
+    /// vector is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// assert!((Vec2::X.angle_to(Vec2::Y) - SignedFractional::FRAC_PI_2).abs() < epsilon);
+    /// assert!((Vec2::Y.angle_to(Vec2::X) + SignedFractional::FRAC_PI_2).abs() < epsilon);
+    /// ```
+    #[must_use]
+    pub fn angle_to(&self, other: Self) -> SignedFractional {
+        trig::atan2(self.cross(other), self.dot(other))
+    }
+
+    /// Creates a new `vec2` rotated a quarter turn counterclockwise from `self`, with the same length
+    ///
+    /// Gives the left hand normal of an edge going along `self` without any trigonometry
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use skala_engine_numerics::Vec2;
+    /// let vector = Vec2::new(3.into(), 1.into());
+    ///
+    /// assert_eq!(vector.perpendicular(), Vec2::new((-1).into(), 3.into()));
+    /// assert_eq!(Vec2::X.perpendicular(), Vec2::Y);
+    /// ```
+    #[must_use]
+    pub fn perpendicular(&self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
     }
 
-    /// Creates a new `vec2` with same direction as `self` but magnitude 1
+    /// Multiplies two vectors as if they were the complex numbers `x + yi`
     ///
-    /// # Panics
-    /// When vector is a zero vector
+    /// Multiplying by a unit vector rotates by that vector's angle, see [`from_angle`](Self::from_angle), so
+    /// multiplying two such rotations composes them. Multiplication is commutative.
     ///
     /// # Example
     ///
     /// ```
     /// # use skala_engine_numerics::Vec2;
-    /// let mut vector = Vec2::new(4.into(), 0.into());
-    /// let normalized = Vec2::new(1.into(), 0.into());
+    /// let vector = Vec2::new(3.into(), 1.into());
     ///
-    /// assert_eq!(vector.get_normalized(), normalized);
+    /// // Multiplying by i is a quarter turn counterclockwise
+    /// assert_eq!(vector.complex_mul(Vec2::Y), Vec2::new((-1).into(), 3.into()));
     /// ```
     #[must_use]
-    pub fn get_normalized(&self) -> Self {
-        let len = self.len();
-
+    pub fn complex_mul(&self, other: Self) -> Self {
         Self {
-            x: self.x / len,
-            y: self.y / len,
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
         }
     }
 
-    #[inline]
-    #[cold]
-    fn considers_this_unlikely_to_happen() {}
-
-    /// Creates a new `vec2` with same direction as `self` but magnitude 1
-    /// If `self` is a zero vector returns None otherwise returns created vector
+    /// Rotates a vector counterclockwise by `radians`
     ///
     /// # Example
     ///
     /// ```
-    /// # use skala_engine_numerics::Vec2;
-    /// let mut vector = Vec2::new(4.into(), 0.into());
-    /// let normalized = Vec2::new(1.into(), 0.into());
+    /// # use skala_engine_numerics::{SignedFractional, Vec2};
+    /// let vector = Vec2::new(2.into(), 0.into());
+    /// let epsilon = SignedFractional::from_num(0.000_1);
     ///
-    /// assert_eq!(vector.try_get_normalized(), Some(normalized));
-    /// assert_eq!(Vec2::ZERO.try_get_normalized(), None);
+    /// let rotated = vector.rotate(SignedFractional::PI);
+    ///
+    /// assert!(rotated.approx_eq(Vec2::new((-2).into(), 0.into()), epsilon));
     /// ```
     #[must_use]
-    pub fn try_get_normalized(&self) -> Option<Self> {
-        let len = self.len();
+    pub fn rotate(&self, radians: SignedFractional) -> Self {
+        let (sin, cos) = trig::sin_cos(radians);
 
-        if len == SignedFractional::ZERO {
-            Self::considers_this_unlikely_to_happen();
-            return None;
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
         }
+    }
+}
 
-        Some(Self {
-            x: self.x / len,
-            y: self.y / len,
-        })
+/// Applies the `3t² - 2t³` easing to `t` clamped to `[0, 1]`
+pub(crate) fn smoothstep_curve<T: Fixed>(t: T) -> T {
+    let t = t.clamp(T::ZERO, T::from_num(1));
+
+    t * t * (T::from_num(3) - T::from_num(2) * t)
+}
+
+impl Default for Vec2 {
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
@@ -152,129 +897,199 @@ impl From<(SignedFractional, SignedFractional)> for Vec2 {
     }
 }
 
-impl From<Vec2> for (SignedFractional, SignedFractional) {
-    fn from(n: Vec2) -> Self {
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(n: Vec2<T>) -> Self {
         (n.x, n.y)
     }
 }
 
-impl Neg for Vec2 {
+impl<T: Display> Display for Vec2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(precision) = f.precision() {
+            write!(f, "({:.*}, {:.*})", precision, self.x, precision, self.y)
+        } else {
+            write!(f, "({}, {})", self.x, self.y)
+        }
+    }
+}
+
+/// Parses a vector written as comma separated coordinates, optionally wrapped in parentheses
+///
+/// # Example
+///
+/// ```
+/// # use skala_engine_numerics::{SignedFractional, Vec2};
+/// let vector: Vec2 = "(1.5, -2)".parse().unwrap();
+///
+/// assert_eq!(vector, Vec2::new(SignedFractional::from_num(1.5), (-2).into()));
+/// ```
+impl FromStr for Vec2 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse::parse_components(s)?;
+
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<T: FixedSigned> Neg for Vec2<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-        }
+        (-Vector::from(self)).into()
     }
 }
 
-impl Add for Vec2 {
+impl<T: FixedSigned> Add for Vec2<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
+        (Vector::from(self) + Vector::from(rhs)).into()
     }
 }
 
-impl Add<(SignedFractional, SignedFractional)> for Vec2 {
+impl<T: FixedSigned> Add<(T, T)> for Vec2<T> {
     type Output = Self;
 
-    fn add(self, rhs: (SignedFractional, SignedFractional)) -> Self::Output {
-        self + Into::<Self>::into(rhs)
+    fn add(self, rhs: (T, T)) -> Self::Output {
+        self + Self { x: rhs.0, y: rhs.1 }
     }
 }
 
-impl AddAssign for Vec2 {
+impl<T: FixedSigned> AddAssign for Vec2<T> {
     fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
+        *self = *self + rhs;
     }
 }
 
-impl AddAssign<(SignedFractional, SignedFractional)> for Vec2 {
-    fn add_assign(&mut self, rhs: (SignedFractional, SignedFractional)) {
+impl<T: FixedSigned> AddAssign<(T, T)> for Vec2<T> {
+    fn add_assign(&mut self, rhs: (T, T)) {
         self.x += rhs.0;
         self.y += rhs.1;
     }
 }
 
-impl Sub for Vec2 {
-    type Output = Vec2;
+impl<T: FixedSigned> Sub for Vec2<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-        }
+        (Vector::from(self) - Vector::from(rhs)).into()
     }
 }
 
-impl Sub<(SignedFractional, SignedFractional)> for Vec2 {
+impl<T: FixedSigned> Sub<(T, T)> for Vec2<T> {
     type Output = Self;
 
-    fn sub(self, rhs: (SignedFractional, SignedFractional)) -> Self::Output {
-        self - Into::<Self>::into(rhs)
+    fn sub(self, rhs: (T, T)) -> Self::Output {
+        self - Self { x: rhs.0, y: rhs.1 }
     }
 }
 
-impl SubAssign for Vec2 {
+impl<T: FixedSigned> SubAssign for Vec2<T> {
     fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
+        *self = *self - rhs;
     }
 }
 
-impl SubAssign<(SignedFractional, SignedFractional)> for Vec2 {
-    fn sub_assign(&mut self, rhs: (SignedFractional, SignedFractional)) {
+impl<T: FixedSigned> SubAssign<(T, T)> for Vec2<T> {
+    fn sub_assign(&mut self, rhs: (T, T)) {
         self.x -= rhs.0;
         self.y -= rhs.1;
     }
 }
 
-impl Mul<SignedFractional> for Vec2 {
+impl<T: FixedSigned> Mul<T> for Vec2<T> {
     type Output = Self;
 
-    fn mul(self, rhs: SignedFractional) -> Self::Output {
-        Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
-        }
+    fn mul(self, rhs: T) -> Self::Output {
+        (Vector::from(self) * rhs).into()
     }
 }
 
-impl MulAssign<SignedFractional> for Vec2 {
-    fn mul_assign(&mut self, rhs: SignedFractional) {
-        self.x *= rhs;
-        self.y *= rhs;
+impl<T: FixedSigned> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
     }
 }
 
-impl Div<SignedFractional> for Vec2 {
+impl<T: FixedSigned> Div<T> for Vec2<T> {
     type Output = Self;
 
-    fn div(self, rhs: SignedFractional) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-        }
+    fn div(self, rhs: T) -> Self::Output {
+        (Vector::from(self) / rhs).into()
+    }
+}
+
+impl<T: FixedSigned> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: FixedSigned> Neg for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<T: FixedSigned> Add<&Vec2<T>> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn add(self, rhs: &Vec2<T>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<T: FixedSigned> Add<&Vec2<T>> for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, rhs: &Vec2<T>) -> Self::Output {
+        self + *rhs
+    }
+}
+
+impl<T: FixedSigned> Sub<&Vec2<T>> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, rhs: &Vec2<T>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<T: FixedSigned> Sub<&Vec2<T>> for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: &Vec2<T>) -> Self::Output {
+        self - *rhs
     }
 }
 
-impl DivAssign<SignedFractional> for Vec2 {
-    fn div_assign(&mut self, rhs: SignedFractional) {
-        self.x /= rhs;
-        self.y /= rhs;
+impl<T: FixedSigned> Mul<T> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl<T: FixedSigned> Div<T> for &Vec2<T> {
+    type Output = Vec2<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        *self / rhs
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::vector::Vec2;
+    use crate::vector::{NormalizeError, ParseVectorError, Vec2, Vec3};
     use crate::SignedFractional;
+    use fixed::traits::FixedSigned;
+    use fixed::types::{I32F32, I48F16};
+    use fixed_sqrt::FixedSqrt;
 
     #[test]
     // Tests that derive(Eq) continues to be correct
@@ -302,6 +1117,17 @@ mod test {
         assert_eq!(x, y);
     }
 
+    #[test]
+    fn default_backing_is_inferred() {
+        // Call sites naming no backing type have to keep compiling with the default one
+        let zero = Vec2::default();
+        let x = Vec2::from((3.into(), 4.into()));
+
+        assert_eq!(zero.len(), 0);
+        assert_eq!(x.len(), 5);
+        assert_eq!(x + (1.into(), 1.into()), Vec2::new(4.into(), 5.into()));
+    }
+
     #[test]
     fn addition() {
         let x = Vec2::new(2.into(), 3.into());
@@ -318,6 +1144,17 @@ mod test {
         assert_eq!(x.len(), 5);
     }
 
+    #[test]
+    fn dot_and_cross() {
+        let x = Vec2::new(2.into(), 3.into());
+        let y = Vec2::new(5.into(), 7.into());
+
+        assert_eq!(x.dot(y), 31);
+        assert_eq!(x.cross(y), -1);
+        assert_eq!(y.cross(x), 1);
+        assert_eq!(x.cross(x * 3.into()), 0);
+    }
+
     #[test]
     fn scalar_multiplication() {
         let x = Vec2::new(3.into(), 4.into());
@@ -343,4 +1180,544 @@ mod test {
         assert_eq!(x.get_normalized(), y);
         assert_eq!(wrong.try_get_normalized(), None)
     }
+
+    #[test]
+    fn approximate_equality() {
+        let x = Vec2::new(3.into(), 7.into()).get_normalized();
+        let y = Vec2::new(6.into(), 14.into()).get_normalized();
+        let nudged = x + Vec2::new(SignedFractional::DELTA, 0.into());
+
+        assert!(x.approx_eq(y, SignedFractional::from_num(0.000_001)));
+        assert!(x.approx_eq(nudged, SignedFractional::DELTA));
+        assert!(!x.approx_eq(nudged, 0.into()));
+        assert!(!x.approx_eq(Vec2::Y, SignedFractional::from_num(0.1)));
+    }
+
+    #[test]
+    fn reductions() {
+        let x = Vec2::new(3.into(), (-4).into());
+        let large = Vec2::new(40_000.into(), 50_000.into());
+
+        assert_eq!(x.element_sum(), -1);
+        assert_eq!(x.element_product(), -12);
+        assert_eq!(large.element_sum(), 90_000);
+        assert_eq!(large.element_product(), 2_000_000_000);
+    }
+
+    #[test]
+    fn mapping() {
+        let x = Vec2::new(3.into(), (-4).into());
+
+        assert_eq!(x.map(|c| c * SignedFractional::from_num(2)), x * 2.into());
+        assert_eq!(x.map(|c| c), x);
+    }
+
+    #[test]
+    fn absolute_value_and_sign() {
+        let x = Vec2::new((-5).into(), 7.into());
+        let y = Vec2::new(0.into(), SignedFractional::from_num(-0.25));
+
+        assert_eq!(x.abs(), Vec2::new(5.into(), 7.into()));
+        assert_eq!(x.signum(), Vec2::new((-1).into(), 1.into()));
+        assert_eq!(
+            y.abs(),
+            Vec2::new(0.into(), SignedFractional::from_num(0.25))
+        );
+        assert_eq!(y.signum(), Vec2::new(0.into(), (-1).into()));
+    }
+
+    #[test]
+    fn rounding() {
+        let x = Vec2::new(
+            SignedFractional::from_num(2.75),
+            SignedFractional::from_num(-2.25),
+        );
+        let half = Vec2::new(
+            SignedFractional::from_num(0.5),
+            SignedFractional::from_num(-3.5),
+        );
+
+        assert_eq!(x.floor(), Vec2::new(2.into(), (-3).into()));
+        assert_eq!(x.ceil(), Vec2::new(3.into(), (-2).into()));
+        assert_eq!(x.round(), Vec2::new(3.into(), (-2).into()));
+        assert_eq!(half.round(), Vec2::new(1.into(), (-4).into()));
+
+        // Integers are left alone
+        let whole = Vec2::new(4.into(), (-7).into());
+        assert_eq!(whole.floor(), whole);
+        assert_eq!(whole.ceil(), whole);
+        assert_eq!(whole.round(), whole);
+    }
+
+    #[test]
+    fn component_min_max() {
+        let x = Vec2::new(2.into(), 9.into());
+        let y = Vec2::new(5.into(), (-1).into());
+
+        assert_eq!(x.min(y), Vec2::new(2.into(), (-1).into()));
+        assert_eq!(x.max(y), Vec2::new(5.into(), 9.into()));
+    }
+
+    #[test]
+    fn component_clamp() {
+        let lo = Vec2::new(0.into(), 0.into());
+        let hi = Vec2::new(10.into(), 10.into());
+
+        assert_eq!(
+            Vec2::new(5.into(), (-3).into()).clamp(lo, hi),
+            Vec2::new(5.into(), 0.into())
+        );
+        assert_eq!(
+            Vec2::new(12.into(), 4.into()).clamp(lo, hi),
+            Vec2::new(10.into(), 4.into())
+        );
+    }
+
+    #[test]
+    fn interpolation() {
+        let x = Vec2::new(2.into(), 4.into());
+        let y = Vec2::new(6.into(), 0.into());
+
+        assert_eq!(x.lerp(y, 0.into()), x);
+        assert_eq!(x.lerp(y, 1.into()), y);
+        assert_eq!(
+            x.lerp(y, SignedFractional::from_num(0.5)),
+            Vec2::new(4.into(), 2.into())
+        );
+    }
+
+    #[test]
+    fn midpoint() {
+        let x = Vec2::new(0.into(), 0.into());
+        let y = Vec2::new(4.into(), 6.into());
+
+        assert_eq!(x.midpoint(y), Vec2::new(2.into(), 3.into()));
+        assert_eq!(y.midpoint(x), Vec2::new(2.into(), 3.into()));
+    }
+
+    #[test]
+    fn magnitude_clamping() {
+        let short = Vec2::new(3.into(), 4.into());
+        let long = Vec2::new(6.into(), 8.into());
+
+        assert_eq!(short.clamp_magnitude(10.into()), short);
+        assert_eq!(long.clamp_magnitude(5.into()), short);
+        assert_eq!(Vec2::ZERO.clamp_magnitude(5.into()), Vec2::ZERO);
+    }
+
+    #[test]
+    fn moving_towards() {
+        let x = Vec2::new(1.into(), 1.into());
+        let target = Vec2::new(7.into(), 9.into());
+
+        assert_eq!(
+            x.move_towards(target, 5.into()),
+            Vec2::new(4.into(), 5.into())
+        );
+        assert_eq!(x.move_towards(target, 12.into()), target);
+        assert_eq!(x.move_towards(target, 0.into()), x);
+        assert_eq!(target.move_towards(target, 1.into()), target);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Vec2::ONE, Vec2::new(1.into(), 1.into()));
+        assert_eq!(Vec2::X, Vec2::new(1.into(), 0.into()));
+        assert_eq!(Vec2::Y, Vec2::new(0.into(), 1.into()));
+        assert_eq!(Vec2::X + Vec2::Y, Vec2::ONE);
+    }
+
+    #[test]
+    fn len_of_large_vectors() {
+        let epsilon = SignedFractional::from_num(0.01);
+
+        let x = Vec2::new(30_000.into(), 40_000.into());
+        assert_eq!(x.len(), 50_000);
+
+        let y = Vec2::new((-48_000).into(), 64_000.into());
+        assert_eq!(y.len(), 80_000);
+
+        let z = Vec2::new(40_000.into(), 40_000.into());
+        assert!((z.len() - SignedFractional::from_num(56_568.542_495)).abs() < epsilon);
+    }
+
+    #[test]
+    fn rotation() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let x = Vec2::new(1.into(), 0.into());
+
+        assert!(x
+            .rotate(SignedFractional::FRAC_PI_2)
+            .approx_eq(Vec2::Y, epsilon));
+        assert!(x
+            .rotate(-SignedFractional::FRAC_PI_2)
+            .approx_eq(-Vec2::Y, epsilon));
+        assert!(x
+            .rotate(SignedFractional::TAU + SignedFractional::PI)
+            .approx_eq(-Vec2::X, epsilon));
+        assert!(x.rotate(SignedFractional::ZERO).approx_eq(x, epsilon));
+    }
+
+    #[test]
+    fn angle_round_trip() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let angles = [
+            SignedFractional::ZERO,
+            SignedFractional::FRAC_PI_6,
+            SignedFractional::FRAC_PI_2,
+            SignedFractional::from_num(2.5),
+            SignedFractional::from_num(3),
+            -SignedFractional::FRAC_PI_4,
+            SignedFractional::from_num(-3),
+        ];
+
+        for angle in angles {
+            let round_trip = Vec2::from_angle(angle).to_angle();
+
+            assert!(
+                (round_trip - angle).abs() < epsilon,
+                "{angle} became {round_trip}"
+            );
+        }
+    }
+
+    #[test]
+    fn extending() {
+        let x = Vec2::new(4.into(), (-5).into());
+
+        assert_eq!(x.extend(6.into()), Vec3::new(4, -5, 6));
+        assert_eq!(x.extend(6.into()).truncate(), x);
+    }
+
+    #[test]
+    fn normalizing_or_zero() {
+        let mut x = Vec2::new((-5).into(), 0.into());
+        let mut zero = Vec2::ZERO;
+
+        assert_eq!(x.normalize_or_zero(), -Vec2::X);
+        assert_eq!(zero.normalize_or_zero(), Vec2::ZERO);
+
+        x.normalize_or_zero_mut();
+        zero.normalize_or_zero_mut();
+
+        assert_eq!(x, -Vec2::X);
+        assert_eq!(zero, Vec2::ZERO);
+    }
+
+    #[test]
+    fn reference_operators() {
+        let x = Vec2::new(1.into(), 2.into());
+        let y = Vec2::new(3.into(), 5.into());
+        let (x_ref, y_ref) = (&x, &y);
+        let three = SignedFractional::from_num(3);
+
+        assert_eq!(x_ref + y_ref, x + y);
+        assert_eq!(y_ref - x_ref, y - x);
+        assert_eq!(y - x_ref, y - x);
+        assert_eq!(-x_ref, -x);
+        assert_eq!(x_ref * three, x * three);
+        assert_eq!(y_ref / three, y / three);
+
+        let points = [x, y, x];
+        let sum = points.iter().fold(Vec2::ZERO, |sum, point| sum + point);
+
+        assert_eq!(sum, Vec2::new(5.into(), 9.into()));
+    }
+
+    #[test]
+    fn try_normalizing() {
+        let x = Vec2::new(0.into(), (-2).into());
+
+        assert_eq!(x.try_normalize(), Ok(-Vec2::Y));
+        assert_eq!(Vec2::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+    }
+
+    #[test]
+    fn manhattan() {
+        let x = Vec2::ZERO;
+        let y = Vec2::new(3.into(), 4.into());
+
+        assert_eq!(x.manhattan_distance(y), 7);
+        assert_eq!(y.manhattan_distance(x), 7);
+        assert_eq!((y - x).len(), 5);
+    }
+
+    #[test]
+    fn chebyshev() {
+        let x = Vec2::ZERO;
+        let y = Vec2::new(3.into(), 4.into());
+
+        assert_eq!(x.chebyshev_distance(y), 4);
+        assert_eq!(y.chebyshev_distance(x), 4);
+        assert_eq!(x.chebyshev_distance(Vec2::new((-6).into(), 2.into())), 6);
+    }
+
+    #[test]
+    fn display() {
+        let x = Vec2::new(SignedFractional::from_num(1.5), (-2).into());
+
+        assert_eq!(x.to_string(), "(1.5, -2)");
+        assert_eq!(format!("{x:.2}"), "(1.50, -2.00)");
+        assert_eq!(format!("{:.0}", Vec2::ZERO), "(0, 0)");
+    }
+
+    #[test]
+    fn parsing() {
+        let expected = Vec2::new(SignedFractional::from_num(1.5), (-2).into());
+
+        assert_eq!("1.5,-2".parse::<Vec2>(), Ok(expected));
+        assert_eq!("  ( 1.5 , -2 )  ".parse::<Vec2>(), Ok(expected));
+        assert_eq!(
+            expected.to_string().parse::<Vec2>(),
+            Ok(expected),
+            "Display output should parse back"
+        );
+    }
+
+    #[test]
+    fn parsing_wrong_component_count() {
+        assert_eq!(
+            "1,2,3".parse::<Vec2>(),
+            Err(ParseVectorError::ComponentCount {
+                expected: 2,
+                found: 3
+            })
+        );
+        assert_eq!(
+            "(1)".parse::<Vec2>(),
+            Err(ParseVectorError::ComponentCount {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            " () ".parse::<Vec2>(),
+            Err(ParseVectorError::ComponentCount {
+                expected: 2,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_components() {
+        assert!(matches!(
+            "1,two".parse::<Vec2>(),
+            Err(ParseVectorError::InvalidComponent { index: 1, .. })
+        ));
+        assert!(matches!(
+            "1,".parse::<Vec2>(),
+            Err(ParseVectorError::InvalidComponent { index: 1, .. })
+        ));
+        assert!(matches!(
+            "(1,2".parse::<Vec2>(),
+            Err(ParseVectorError::InvalidComponent { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn smoothstep() {
+        let start = Vec2::new((-2).into(), 3.into());
+        let end = Vec2::new(6.into(), (-5).into());
+        let half = SignedFractional::from_num(0.5);
+
+        assert_eq!(start.smoothstep(end, 0.into()), start);
+        assert_eq!(start.smoothstep(end, 1.into()), end);
+        assert_eq!(start.smoothstep(end, half), start.lerp(end, half));
+        assert_eq!(start.smoothstep(end, (-1).into()), start);
+        assert_eq!(start.smoothstep(end, 3.into()), end);
+        // Eases in, moving less than linear interpolation near the start
+        let quarter = SignedFractional::from_num(0.25);
+        assert!(
+            start.smoothstep(end, quarter).manhattan_distance(start)
+                < start.lerp(end, quarter).manhattan_distance(start)
+        );
+    }
+
+    #[test]
+    fn nlerp() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let from = Vec2::new(3.into(), 4.into()).get_normalized();
+        let to = Vec2::new((-4).into(), 3.into()).get_normalized();
+
+        assert!(from.nlerp(to, 0.into()).approx_eq(from, epsilon));
+        assert!(from.nlerp(to, 1.into()).approx_eq(to, epsilon));
+
+        for step in 0..=8 {
+            let t = SignedFractional::from_num(step) / SignedFractional::from_num(8);
+            let blended = from.nlerp(to, t);
+
+            assert!((blended.len() - SignedFractional::ONE).abs() < epsilon);
+        }
+    }
+
+    #[test]
+    fn nlerp_opposite_directions() {
+        let half = SignedFractional::from_num(0.5);
+        let nearly_opposite = Vec2::new((-1).into(), SignedFractional::DELTA);
+
+        assert_eq!(Vec2::X.nlerp(-Vec2::X, half), -Vec2::X);
+        assert_eq!(
+            Vec2::X.nlerp(-Vec2::X, half - SignedFractional::DELTA),
+            Vec2::X
+        );
+
+        let blended = Vec2::X.nlerp(nearly_opposite, half);
+        assert!((blended.len() - SignedFractional::ONE).abs() < SignedFractional::from_num(0.001));
+    }
+
+    #[test]
+    fn reciprocal() {
+        let scale = Vec2::new(4.into(), SignedFractional::from_num(-0.5));
+
+        assert_eq!(
+            scale.recip(),
+            Vec2::new(SignedFractional::from_num(0.25), (-2).into())
+        );
+        assert_eq!(scale.try_recip(), Some(scale.recip()));
+        assert_eq!(Vec2::new(0.into(), 2.into()).try_recip(), None);
+        assert_eq!(Vec2::new(2.into(), 0.into()).try_recip(), None);
+        assert_eq!(
+            Vec2::new(1.into(), SignedFractional::DELTA).try_recip(),
+            None
+        );
+    }
+
+    #[test]
+    fn dot_with_tuple() {
+        let x = Vec2::new(3.into(), (-2).into());
+        let y = (SignedFractional::from_num(1.5), 4.into());
+
+        assert_eq!(x.dot_tuple(y), x.dot(y.into()));
+        assert_eq!(x.dot_tuple(y), SignedFractional::from_num(-3.5));
+    }
+
+    #[test]
+    fn signed_angle_between_vectors() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let down = Vec2::new(0.into(), (-1).into());
+        let diagonal = Vec2::new((-3).into(), 3.into());
+
+        assert!((Vec2::X.angle_to(Vec2::Y) - SignedFractional::FRAC_PI_2).abs() < epsilon);
+        assert!((Vec2::X.angle_to(down) + SignedFractional::FRAC_PI_2).abs() < epsilon);
+        assert!((Vec2::X.angle_to(-Vec2::X) - SignedFractional::PI).abs() < epsilon);
+        assert!(
+            (Vec2::Y.angle_to(diagonal) - SignedFractional::FRAC_PI_4).abs() < epsilon,
+            "Angle doesn't depend on length"
+        );
+        assert_eq!(Vec2::X.angle_to(Vec2::X), 0);
+        assert_eq!(Vec2::X.angle_to(Vec2::ZERO), 0);
+    }
+
+    #[test]
+    fn perpendicular() {
+        let x = Vec2::new(3.into(), (-4).into());
+        let perpendicular = x.perpendicular();
+
+        assert_eq!(x.dot(perpendicular), 0);
+        assert_eq!(perpendicular.len(), x.len());
+        assert!(
+            x.cross(perpendicular) > SignedFractional::ZERO,
+            "Rotation is counterclockwise"
+        );
+        assert_eq!(perpendicular.perpendicular(), -x);
+    }
+
+    #[test]
+    fn complex_multiplication() {
+        let x = Vec2::new(2.into(), (-3).into());
+        let y = Vec2::new(4.into(), 5.into());
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        assert_eq!(x.complex_mul(Vec2::X), x);
+        assert_eq!(x.complex_mul(Vec2::Y), x.perpendicular());
+        assert_eq!(x.complex_mul(y), Vec2::new(23.into(), (-2).into()));
+        assert_eq!(x.complex_mul(y), y.complex_mul(x));
+
+        // Composing two rotations adds their angles
+        let composed = Vec2::from_angle(1.into())
+            .complex_mul(Vec2::from_angle(SignedFractional::from_num(0.5)));
+        assert!(composed.approx_eq(Vec2::from_angle(SignedFractional::from_num(1.5)), epsilon));
+        assert!(x
+            .complex_mul(Vec2::from_angle(1.into()))
+            .approx_eq(x.rotate(1.into()), epsilon));
+    }
+
+    #[test]
+    fn polar_round_trip() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        for point in [
+            Vec2::new(3.into(), 4.into()),
+            Vec2::new((-5).into(), 12.into()),
+            Vec2::new((-1).into(), (-1).into()),
+            Vec2::new(SignedFractional::from_num(0.5), (-2).into()),
+        ] {
+            let (radius, angle) = point.to_polar();
+
+            assert!(Vec2::from_polar(radius, angle).approx_eq(point, epsilon));
+        }
+
+        assert_eq!(Vec2::new(3.into(), 4.into()).to_polar().0, 5);
+        assert_eq!(Vec2::ZERO.to_polar(), (0.into(), 0.into()));
+        assert_eq!(Vec2::from_polar(0.into(), 1.into()), Vec2::ZERO);
+    }
+
+    fn arithmetic_with_backing<T: FixedSigned + FixedSqrt>() {
+        let x = Vec2 {
+            x: T::from_num(3),
+            y: T::from_num(-4),
+        };
+        let y = Vec2 {
+            x: T::from_num(1),
+            y: T::from_num(2),
+        };
+        let epsilon = T::from_num(0.001);
+
+        assert_eq!(x.len(), T::from_num(5));
+        assert_eq!(x.dot(y), T::from_num(-5));
+        assert_eq!(x.cross(y), T::from_num(10));
+        assert_eq!(x + y - y, x);
+        assert_eq!(
+            (x * T::from_num(2)).abs(),
+            Vec2 {
+                x: T::from_num(6),
+                y: T::from_num(8)
+            }
+        );
+        assert_eq!(
+            x.lerp(y, T::from_num(0.5)),
+            Vec2 {
+                x: T::from_num(2),
+                y: T::from_num(-1)
+            }
+        );
+        assert!(x.get_normalized().approx_eq(
+            Vec2 {
+                x: T::from_num(0.6),
+                y: T::from_num(-0.8)
+            },
+            epsilon
+        ));
+        let zero = Vec2 {
+            x: T::ZERO,
+            y: T::ZERO,
+        };
+        assert_eq!(zero.normalize_or_zero(), zero);
+    }
+
+    #[test]
+    fn generic_backing_types() {
+        arithmetic_with_backing::<I32F32>();
+        arithmetic_with_backing::<I48F16>();
+
+        // Out of range for I32F32 but not for I48F16
+        let far = Vec2 {
+            x: I48F16::from_num(3_000_000_000_i64),
+            y: I48F16::from_num(4_000_000_000_i64),
+        };
+
+        assert_eq!(far.len(), I48F16::from_num(5_000_000_000_i64));
+        assert_eq!(far.extend(I48F16::ZERO).truncate(), far);
+    }
 }
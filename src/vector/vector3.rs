@@ -1,16 +1,22 @@
-use crate::SignedFractional;
+use crate::vector::{parse, smoothstep_curve, NormalizeError, ParseVectorError, Vec2, Vector};
+use crate::{trig, SignedFractional};
+use fixed::traits::FixedSigned;
 use fixed_sqrt::FixedSqrt;
+use std::fmt::{Display, Formatter};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /// A 3d vector.
-#[derive(Eq, PartialEq, Debug, Default, Hash, Copy, Clone)]
-pub struct Vec3 {
+///
+/// Backed by [`SignedFractional`] unless another signed fixed point type is picked with `T`, see [`Vec2`].
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Vec3<T = SignedFractional> {
     #[allow(missing_docs)]
-    pub x: SignedFractional,
+    pub x: T,
     #[allow(missing_docs)]
-    pub y: SignedFractional,
+    pub y: T,
     #[allow(missing_docs)]
-    pub z: SignedFractional,
+    pub z: T,
 }
 
 impl Vec3 {
@@ -21,6 +27,34 @@ impl Vec3 {
         z: SignedFractional::ZERO,
     };
 
+    /// A vector with all coordinates set to one
+    pub const ONE: Self = Self {
+        x: SignedFractional::ONE,
+        y: SignedFractional::ONE,
+        z: SignedFractional::ONE,
+    };
+
+    /// A unit vector pointing along the x axis
+    pub const X: Self = Self {
+        x: SignedFractional::ONE,
+        y: SignedFractional::ZERO,
+        z: SignedFractional::ZERO,
+    };
+
+    /// A unit vector pointing along the y axis
+    pub const Y: Self = Self {
+        x: SignedFractional::ZERO,
+        y: SignedFractional::ONE,
+        z: SignedFractional::ZERO,
+    };
+
+    /// A unit vector pointing along the z axis
+    pub const Z: Self = Self {
+        x: SignedFractional::ZERO,
+        y: SignedFractional::ZERO,
+        z: SignedFractional::ONE,
+    };
+
     /// Creates a new [`Vec3`] from coordinates
     ///
     /// # Examples
@@ -41,7 +75,9 @@ impl Vec3 {
             z: z.into(),
         }
     }
+}
 
+impl<T: FixedSigned + FixedSqrt> Vec3<T> {
     /// Returns the magnitude of this [`Vec3`] raised to the power of two.
     ///
     /// # Examples
@@ -50,24 +86,92 @@ impl Vec3 {
     /// let x = Vec3::new(1, 0, 0);
     ///
     /// // Proving we're working with a unit vector
-    /// assert_eq!(x.magnitude_pow2(), 1);
+    /// assert_eq!(x.len_pow2(), 1);
     /// ```
     #[must_use]
-    pub fn magnitude_pow2(&self) -> SignedFractional {
-        self.x * self.x + self.y * self.y + self.z * self.z
+    pub fn len_pow2(&self) -> T {
+        Vector::from(*self).len_pow2()
     }
 
     /// Returns the magnitude of this [`Vec3`]
+    ///
+    /// Works even when [`len_pow2`](Self::len_pow2) would overflow as long as the magnitude itself fits into the
+    /// backing type.
+    ///
     /// # Examples
     /// ```
     /// # use skala_engine_numerics::vector::Vec3;
     /// let x = Vec3::new(2, 4, 4);
+    /// let big = Vec3::new(40_000, 80_000, 80_000);
+    ///
+    /// assert_eq!(x.len(), 6);
+    /// assert_eq!(big.len(), 120_000);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> T {
+        Vector::from(*self).len()
+    }
+
+    /// Returns the magnitude of this [`Vec3`] raised to the power of two.
+    #[must_use]
+    #[deprecated(note = "use `len_pow2` which matches the name used by `Vec2`")]
+    pub fn magnitude_pow2(&self) -> T {
+        self.len_pow2()
+    }
+
+    /// Returns the magnitude of this [`Vec3`]
+    #[must_use]
+    #[deprecated(note = "use `len` which matches the name used by `Vec2`")]
+    pub fn magnitude(&self) -> T {
+        self.len()
+    }
+
+    /// Returns the dot product of this [`Vec3`] and `other`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(1, 2, 3);
+    /// let y = Vec3::new(4, -5, 6);
+    ///
+    /// assert_eq!(x.dot(y), 12);
+    /// ```
+    #[must_use]
+    pub fn dot(&self, other: Self) -> T {
+        Vector::from(*self).dot(other.into())
+    }
+
+    /// Returns the dot product of this [`Vec3`] and a tuple of coordinates without building a temporary [`Vec3`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(x.dot_tuple((4.into(), (-5).into(), 6.into())), 12);
+    /// ```
+    #[must_use]
+    pub fn dot_tuple(&self, other: (T, T, T)) -> T {
+        self.x * other.0 + self.y * other.1 + self.z * other.2
+    }
+
+    /// Returns the cross product of this [`Vec3`] and `other`
+    ///
+    /// The result is perpendicular to both vectors and follows the right hand rule
     ///
-    /// assert_eq!(x.magnitude(), 6);
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// assert_eq!(Vec3::X.cross(Vec3::Y), Vec3::Z);
+    /// assert_eq!(Vec3::Y.cross(Vec3::X), -Vec3::Z);
     /// ```
     #[must_use]
-    pub fn magnitude(&self) -> SignedFractional {
-        self.magnitude_pow2().sqrt()
+    pub fn cross(&self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
     }
 
     /// Sets the magnitude of this [`Vec3`] to one
@@ -81,68 +185,796 @@ impl Vec3 {
     /// let mut x = Vec3::new(20, 0, 0);
     ///
     /// // Before normalization
-    /// assert_eq!(x.magnitude(), 20);
+    /// assert_eq!(x.len(), 20);
+    ///
+    /// x.normalize();
+    /// // After normalization
+    /// assert_eq!(x.len(), 1);
+    pub fn normalize(&mut self) {
+        *self /= self.len();
+    }
+
+    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`]
+    ///
+    /// # Panics
+    /// If vector magnitude is 0
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(10, 0, 0);
+    ///
+    /// assert_eq!(x.get_normalized(), Vec3::new(1, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn get_normalized(&self) -> Self {
+        Vector::from(*self).get_normalized().into()
+    }
+
+    /// Creates a [`Vec2`] from the x and y coordinates of this [`Vec3`], dropping z
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::{Vec2, Vec3};
+    /// let x = Vec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(x.truncate(), Vec2::new(1.into(), 2.into()));
+    /// ```
+    #[must_use]
+    pub fn truncate(&self) -> Vec2<T> {
+        Vec2 {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Vec3 {
+    /// Creates a [`Vec3`] from spherical coordinates
+    ///
+    /// `theta` is the polar angle in radians measured from the positive y axis and `phi` the azimuth in radians around
+    /// the y axis, measured from the positive z axis towards the positive x axis like [`orbit`](crate::camera::orbit)
+    /// does.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let x = Vec3::from_spherical(2.into(), SignedFractional::FRAC_PI_2, SignedFractional::FRAC_PI_2);
+    ///
+    /// assert!(x.approx_eq(Vec3::new(2, 0, 0), epsilon));
+    /// ```
+    #[must_use]
+    pub fn from_spherical(
+        radius: SignedFractional,
+        theta: SignedFractional,
+        phi: SignedFractional,
+    ) -> Self {
+        let (theta_sin, theta_cos) = trig::sin_cos(theta);
+        let (phi_sin, phi_cos) = trig::sin_cos(phi);
+
+        Self {
+            x: radius * theta_sin * phi_sin,
+            y: radius * theta_cos,
+            z: radius * theta_sin * phi_cos,
+        }
+    }
+
+    /// Returns the spherical coordinates `(radius, theta, phi)` of this [`Vec3`]
+    ///
+    /// Uses the same convention as [`from_spherical`](Self::from_spherical). `theta` lies in `[0, π]` and `phi` in
+    /// `(-π, π]`. On the y axis the azimuth is undefined and `phi` is zero, the zero vector has all coordinates zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let epsilon = SignedFractional::from_num(0.000_1);
+    ///
+    /// let (radius, theta, phi) = Vec3::new(0, -3, 0).to_spherical();
+    ///
+    /// assert_eq!(radius, 3);
+    /// assert!((theta - SignedFractional::PI).abs() < epsilon);
+    /// assert_eq!(phi, 0);
+    /// ```
+    #[must_use]
+    pub fn to_spherical(&self) -> (SignedFractional, SignedFractional, SignedFractional) {
+        let horizontal = Vec2::new(self.x, self.z).len();
+
+        (
+            self.len(),
+            trig::atan2(horizontal, self.y),
+            trig::atan2(self.x, self.z),
+        )
+    }
+}
+
+impl<T: FixedSigned + FixedSqrt> Vec3<T> {
+    /// Adds `other` to this [`Vec3`], returning `None` if any component overflows
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let big = Vec3::new(SignedFractional::MAX, 0, 0);
+    ///
+    /// assert_eq!(Vec3::new(1, 2, 3).checked_add(Vec3::ONE), Some(Vec3::new(2, 3, 4)));
+    /// assert_eq!(big.checked_add(Vec3::ONE), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(other.x)?,
+            y: self.y.checked_add(other.y)?,
+            z: self.z.checked_add(other.z)?,
+        })
+    }
+
+    /// Subtracts `other` from this [`Vec3`], returning `None` if any component overflows
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let small = Vec3::new(0, SignedFractional::MIN, 0);
+    ///
+    /// assert_eq!(Vec3::new(1, 2, 3).checked_sub(Vec3::ONE), Some(Vec3::new(0, 1, 2)));
+    /// assert_eq!(small.checked_sub(Vec3::ONE), None);
+    /// ```
+    #[must_use]
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(other.x)?,
+            y: self.y.checked_sub(other.y)?,
+            z: self.z.checked_sub(other.z)?,
+        })
+    }
+
+    /// Multiplies this [`Vec3`] by a scalar, returning `None` if any component overflows
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let big = Vec3::new(0, 0, SignedFractional::MAX);
+    ///
+    /// assert_eq!(Vec3::new(1, 2, 3).checked_mul(2.into()), Some(Vec3::new(2, 4, 6)));
+    /// assert_eq!(big.checked_mul(2.into()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_mul(scalar)?,
+            y: self.y.checked_mul(scalar)?,
+            z: self.z.checked_mul(scalar)?,
+        })
+    }
+
+    /// Divides this [`Vec3`] by a scalar, returning `None` if any component overflows or `scalar` is zero
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// assert_eq!(Vec3::new(2, 4, 6).checked_div(2.into()), Some(Vec3::new(1, 2, 3)));
+    /// assert_eq!(Vec3::ONE.checked_div(0.into()), None);
+    /// ```
+    #[must_use]
+    pub fn checked_div(&self, scalar: T) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_div(scalar)?,
+            y: self.y.checked_div(scalar)?,
+            z: self.z.checked_div(scalar)?,
+        })
+    }
+
+    /// Checks whether every component of this [`Vec3`] differs from the matching component of `other` by at most `epsilon`
+    ///
+    /// Useful when comparing results of calculations involving square roots, which are rarely exact
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec3::new(1, 1, 1).get_normalized();
+    /// let third = SignedFractional::from_num(0.57735027);
+    ///
+    /// assert!(x.approx_eq(Vec3::new(third, third, third), SignedFractional::from_num(0.000_001)));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: Self, epsilon: T) -> bool {
+        Vector::from(*self).approx_eq(other.into(), epsilon)
+    }
+
+    /// Returns the [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry) between this [`Vec3`] and
+    /// `other`, the sum of the absolute differences of their components
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(1, 1, 1);
+    /// let y = Vec3::new(4, -3, 2);
+    ///
+    /// assert_eq!(x.manhattan_distance(y), 8);
+    /// ```
+    #[must_use]
+    pub fn manhattan_distance(&self, other: Self) -> T {
+        (*self - other).abs().element_sum()
+    }
+
+    /// Returns the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) between this [`Vec3`] and
+    /// `other`, the largest absolute difference of their components
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(1, 1, 1);
+    /// let y = Vec3::new(4, -3, 2);
+    ///
+    /// assert_eq!(x.chebyshev_distance(y), 4);
+    /// ```
+    #[must_use]
+    pub fn chebyshev_distance(&self, other: Self) -> T {
+        let difference = (*self - other).abs();
+
+        difference.x.max(difference.y).max(difference.z)
+    }
+
+    /// Returns the sum of the components of this [`Vec3`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(2, 3, 4);
+    ///
+    /// assert_eq!(x.element_sum(), 9);
+    /// ```
+    #[must_use]
+    pub fn element_sum(&self) -> T {
+        self.x + self.y + self.z
+    }
+
+    /// Returns the product of the components of this [`Vec3`]
+    ///
+    /// Handy for computing the volume of a box from its size.
+    ///
+    /// # Panics
+    /// When the product doesn't fit into `T` and overflow checks are enabled.
+    /// With the default [`SignedFractional`] backing that happens as soon as the product exceeds about 2.1 billion.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(2, 3, 4);
+    ///
+    /// assert_eq!(x.element_product(), 24);
+    /// ```
+    #[must_use]
+    pub fn element_product(&self) -> T {
+        self.x * self.y * self.z
+    }
+
+    /// Creates a [`Vec3`] by applying `f` to each component of this [`Vec3`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec3::new(1, 2, 3);
+    ///
+    /// assert_eq!(x.map(|c| c + SignedFractional::from_num(1)), Vec3::new(2, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        Vector::from(*self).map(f).into()
+    }
+
+    /// Creates a [`Vec3`] made of the absolute values of the components of this [`Vec3`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(-3, 2, -1);
+    ///
+    /// assert_eq!(x.abs(), Vec3::new(3, 2, 1));
+    /// ```
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            x: self.x.abs(),
+            y: self.y.abs(),
+            z: self.z.abs(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the signs of the components of this [`Vec3`]
+    ///
+    /// Each component is `1` if positive, `-1` if negative and `0` if zero
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(-3, 0, 5);
+    ///
+    /// assert_eq!(x.signum(), Vec3::new(-1, 0, 1));
+    /// ```
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the components of this [`Vec3`] rounded down to the nearest integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec3::new(SignedFractional::from_num(1.5), SignedFractional::from_num(-1.5), 3);
+    ///
+    /// assert_eq!(x.floor(), Vec3::new(1, -2, 3));
+    /// ```
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+            z: self.z.floor(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the components of this [`Vec3`] rounded up to the nearest integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec3::new(SignedFractional::from_num(1.5), SignedFractional::from_num(-1.5), 3);
+    ///
+    /// assert_eq!(x.ceil(), Vec3::new(2, -1, 3));
+    /// ```
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+            z: self.z.ceil(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the components of this [`Vec3`] rounded to the nearest integer
+    ///
+    /// Components halfway between two integers are rounded away from zero
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec3::new(SignedFractional::from_num(1.25), SignedFractional::from_num(-1.5), 3);
+    ///
+    /// assert_eq!(x.round(), Vec3::new(1, -2, 3));
+    /// ```
+    #[must_use]
+    pub fn round(&self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+            z: self.z.round(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the reciprocals of the components of this [`Vec3`]
+    ///
+    /// Useful for turning a scale into its inverse
+    ///
+    /// # Panics
+    /// If any component is zero or so close to zero that its reciprocal overflows, see [`try_recip`](Self::try_recip)
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let scale = Vec3::new(2, -4, 1);
+    ///
+    /// assert_eq!(scale.recip(), Vec3::new(SignedFractional::from_num(0.5), SignedFractional::from_num(-0.25), 1));
+    /// ```
+    #[must_use]
+    pub fn recip(&self) -> Self {
+        Self {
+            x: self.x.recip(),
+            y: self.y.recip(),
+            z: self.z.recip(),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the reciprocals of the components of this [`Vec3`] or `None` if any of them can't
+    /// be represented
+    ///
+    /// Returns `None` when a component is zero or so close to zero that its reciprocal overflows
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let scale = Vec3::new(2, -4, 1);
+    ///
+    /// assert_eq!(scale.try_recip(), Some(scale.recip()));
+    /// assert_eq!(Vec3::new(2, 0, 1).try_recip(), None);
+    /// ```
+    #[must_use]
+    pub fn try_recip(&self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_recip()?,
+            y: self.y.checked_recip()?,
+            z: self.z.checked_recip()?,
+        })
+    }
+
+    /// Creates a [`Vec3`] made of the smaller of each pair of components
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let a = Vec3::new(1, 5, 3);
+    /// let b = Vec3::new(3, 2, 3);
+    ///
+    /// assert_eq!(a.min(b), Vec3::new(1, 2, 3));
+    /// ```
+    #[must_use]
+    pub fn min(&self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+            z: self.z.min(other.z),
+        }
+    }
+
+    /// Creates a [`Vec3`] made of the larger of each pair of components
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let a = Vec3::new(1, 5, 3);
+    /// let b = Vec3::new(3, 2, 3);
+    ///
+    /// assert_eq!(a.max(b), Vec3::new(3, 5, 3));
+    /// ```
+    #[must_use]
+    pub fn max(&self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+            z: self.z.max(other.z),
+        }
+    }
+
+    /// Restricts every component of this [`Vec3`] to lie between the matching components of `lo` and `hi`
+    ///
+    /// If a component of `lo` is greater than the matching component of `hi` the `hi` component wins
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let lo = Vec3::new(0, 0, 0);
+    /// let hi = Vec3::new(10, 10, 10);
+    ///
+    /// assert_eq!(Vec3::new(15, 5, -5).clamp(lo, hi), Vec3::new(10, 5, 0));
+    /// ```
+    #[must_use]
+    pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    /// Linearly interpolates between this [`Vec3`] and `other`
+    ///
+    /// `t` equal to zero returns this [`Vec3`] and `t` equal to one returns `other`, values outside of that range
+    /// extrapolate
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let a = Vec3::new(0, 0, 0);
+    /// let b = Vec3::new(4, 8, -4);
+    ///
+    /// assert_eq!(a.lerp(b, SignedFractional::from_num(0.25)), Vec3::new(1, 2, -1));
+    /// ```
+    #[must_use]
+    pub fn lerp(&self, other: Self, t: T) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Interpolates between the unit directions this [`Vec3`] and `other` and normalizes the result
+    ///
+    /// A cheap approximation of spherical interpolation, both inputs should be unit length. When the directions are
+    /// opposite and the interpolation passes through zero the closer of the two inputs is returned instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let blended = Vec3::X.nlerp(Vec3::Z, SignedFractional::from_num(0.5));
+    /// let expected = Vec3::new(1, 0, 1).get_normalized();
+    ///
+    /// assert!(blended.approx_eq(expected, SignedFractional::from_num(0.000_01)));
+    /// ```
+    #[must_use]
+    pub fn nlerp(&self, other: Self, t: T) -> Self {
+        self.lerp(other, t).try_get_normalized().unwrap_or_else(|| {
+            if t < T::from_num(0.5) {
+                *self
+            } else {
+                other
+            }
+        })
+    }
+
+    /// Interpolates between this [`Vec3`] and `other` easing in and out with the `3t² - 2t³` smoothstep curve
+    ///
+    /// `t` is clamped to `[0, 1]`, zero returns this [`Vec3`] and one returns `other`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let a = Vec3::new(0, 0, 0);
+    /// let b = Vec3::new(4, 8, -4);
+    ///
+    /// assert_eq!(a.smoothstep(b, SignedFractional::from_num(0.5)), Vec3::new(2, 4, -2));
+    /// assert_eq!(a.smoothstep(b, 2.into()), b);
+    /// ```
+    #[must_use]
+    pub fn smoothstep(&self, other: Self, t: T) -> Self {
+        self.lerp(other, smoothstep_curve(t))
+    }
+
+    /// Returns the point halfway between this [`Vec3`] and `other`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let a = Vec3::new(0, 0, 0);
+    /// let b = Vec3::new(4, 6, 8);
+    ///
+    /// assert_eq!(a.midpoint(b), Vec3::new(2, 3, 4));
+    /// ```
+    #[must_use]
+    pub fn midpoint(&self, other: Self) -> Self {
+        (*self + other) / T::from_num(2)
+    }
+
+    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(10, 0, 0);
+    /// let zero = Vec3::new(0, 0, 0);
+    ///
+    /// assert_eq!(x.try_get_normalized(), Some(Vec3::new(1, 0, 0)));
+    /// assert_eq!(zero.try_get_normalized(), None);
+    /// ```
+    #[must_use]
+    pub fn try_get_normalized(&self) -> Option<Self> {
+        Vector::from(*self).try_get_normalized().map(Self::from)
+    }
+
+    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`]
+    ///
+    /// # Errors
+    /// Returns [`NormalizeError::ZeroVector`] if this [`Vec3`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::{NormalizeError, Vec3};
+    /// let x = Vec3::new(0, 10, 0);
+    ///
+    /// assert_eq!(x.try_normalize(), Ok(Vec3::new(0, 1, 0)));
+    /// assert_eq!(Vec3::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, NormalizeError> {
+        self.try_get_normalized().ok_or(NormalizeError::ZeroVector)
+    }
+
+    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`] or a zero vector if this
+    /// [`Vec3`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let x = Vec3::new(0, 0, 7);
+    ///
+    /// assert_eq!(x.normalize_or_zero(), Vec3::new(0, 0, 1));
+    /// assert_eq!(Vec3::ZERO.normalize_or_zero(), Vec3::ZERO);
+    /// ```
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.try_get_normalized().unwrap_or(Self {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+        })
+    }
+
+    /// Sets the magnitude of this [`Vec3`] to one, leaving zero vectors unchanged
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let mut x = Vec3::new(0, 0, 7);
+    /// let mut zero = Vec3::ZERO;
+    ///
+    /// x.normalize_or_zero_mut();
+    /// zero.normalize_or_zero_mut();
+    ///
+    /// assert_eq!(x, Vec3::new(0, 0, 1));
+    /// assert_eq!(zero, Vec3::ZERO);
+    /// ```
+    pub fn normalize_or_zero_mut(&mut self) {
+        *self = self.normalize_or_zero();
+    }
+
+    /// Returns the scalar triple product `self.dot(b.cross(c))`
+    ///
+    /// Its absolute value is the volume of the parallelepiped spanned by the three vectors, it's positive when they
+    /// form a right handed system and zero when they're coplanar.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let a = Vec3::new(2, 0, 0);
+    /// let b = Vec3::new(0, 3, 0);
+    /// let c = Vec3::new(1, 1, 4);
+    ///
+    /// assert_eq!(a.scalar_triple(b, c), 24);
+    /// assert_eq!(a.scalar_triple(c, b), -24);
+    /// ```
+    #[must_use]
+    pub fn scalar_triple(&self, b: Self, c: Self) -> T {
+        self.dot(b.cross(c))
+    }
+
+    /// Returns the unit direction halfway between this [`Vec3`] and `other` along the great circle joining them
+    ///
+    /// Equivalent to a spherical interpolation at `t = 0.5` but much cheaper. When the vectors point in opposite
+    /// directions every perpendicular direction is halfway between them and an arbitrary one is returned.
+    ///
+    /// # Panics
+    /// If either vector is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let half_sqrt_2 = SignedFractional::SQRT_2 / SignedFractional::from_num(2);
+    /// let epsilon = SignedFractional::from_num(0.000_001);
+    ///
+    /// let midpoint = Vec3::X.slerp_midpoint(Vec3::new(0, 0, 5));
+    ///
+    /// assert!(midpoint.approx_eq(Vec3::new(half_sqrt_2, 0, half_sqrt_2), epsilon));
+    /// ```
+    #[must_use]
+    pub fn slerp_midpoint(&self, other: Self) -> Self {
+        let from = self.get_normalized();
+        let to = other.get_normalized();
+
+        (from + to)
+            .try_get_normalized()
+            .unwrap_or_else(|| from.any_perpendicular())
+    }
+
+    /// Returns some unit vector perpendicular to this non zero [`Vec3`]
+    fn any_perpendicular(&self) -> Self {
+        // Crossing with the axis this vector is least aligned with keeps the result far from zero
+        let (zero, one) = (T::ZERO, T::from_num(1));
+        let axis = if self.x.abs() <= self.y.abs() && self.x.abs() <= self.z.abs() {
+            Self {
+                x: one,
+                y: zero,
+                z: zero,
+            }
+        } else if self.y.abs() <= self.z.abs() {
+            Self {
+                x: zero,
+                y: one,
+                z: zero,
+            }
+        } else {
+            Self {
+                x: zero,
+                y: zero,
+                z: one,
+            }
+        };
+
+        self.cross(axis).get_normalized()
+    }
+
+    /// Returns two unit vectors perpendicular to this [`Vec3`] and to each other
+    ///
+    /// This [`Vec3`] doesn't have to be normalized. Together with it normalized the returned `(tangent, bitangent)`
+    /// form a right handed orthonormal basis, `tangent.cross(bitangent)` points the same way as this [`Vec3`].
+    /// The tangent is built from the coordinate axis least aligned with this [`Vec3`] so the result stays accurate
+    /// even for vectors lying close to an axis. Useful for placing things around a surface normal.
+    ///
+    /// # Panics
+    /// If this [`Vec3`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec3;
+    /// let (tangent, bitangent) = Vec3::new(0, 0, 5).any_orthonormal_basis();
     ///
-    /// x.normalize();
-    /// // After normalization
-    /// assert_eq!(x.magnitude(), 1);
-    pub fn normalize(&mut self) {
-        *self /= self.magnitude();
+    /// assert_eq!(tangent.dot(Vec3::Z), 0);
+    /// assert_eq!(bitangent.dot(Vec3::Z), 0);
+    /// assert_eq!(tangent.cross(bitangent), Vec3::Z);
+    /// ```
+    #[must_use]
+    pub fn any_orthonormal_basis(&self) -> (Self, Self) {
+        let normal = self.get_normalized();
+        let tangent = normal.any_perpendicular();
+        let bitangent = normal.cross(tangent).get_normalized();
+
+        (tangent, bitangent)
     }
 
-    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`]
+    /// Creates a [`Vec3`] with rotation equal to this [`Vec3`] and magnitude at most `max_len`
     ///
-    /// # Panics
-    /// If vector magnitude is 0
+    /// Vectors with magnitude already within `max_len` (including the zero vector) are returned unchanged
     ///
     /// # Examples
     /// ```
     /// # use skala_engine_numerics::vector::Vec3;
-    /// let x = Vec3::new(10, 0, 0);
+    /// let velocity = Vec3::new(4, 8, 8);
     ///
-    /// assert_eq!(x.get_normalized(), Vec3::new(1, 0, 0));
+    /// assert_eq!(velocity.clamp_magnitude(6.into()), Vec3::new(2, 4, 4));
+    /// assert_eq!(velocity.clamp_magnitude(20.into()), velocity);
     /// ```
     #[must_use]
-    pub fn get_normalized(&self) -> Self {
-        let len = self.magnitude();
+    pub fn clamp_magnitude(&self, max_len: T) -> Self {
+        let len = self.len();
 
-        Self {
-            x: self.x / len,
-            y: self.y / len,
-            z: self.z / len,
+        if len <= max_len || len == T::ZERO {
+            return *self;
         }
-    }
 
-    #[inline]
-    #[cold]
-    /// stable equivalent of `std::intrinsics::unlikely`
-    fn considers_this_unlikely_to_happen() {}
+        *self * (max_len / len)
+    }
 
-    /// Creates a [`Vec3`] with magnitude equal to one and rotation equal to this [`Vec3`]
+    /// Moves this [`Vec3`] towards `target` by at most `max_delta`
+    ///
+    /// Returns `target` if it's closer than `max_delta`, so the result never overshoots
     ///
     /// # Examples
     /// ```
     /// # use skala_engine_numerics::vector::Vec3;
-    /// let x = Vec3::new(10, 0, 0);
-    /// let zero = Vec3::new(0, 0, 0);
+    /// let position = Vec3::new(0, 0, 0);
+    /// let target = Vec3::new(0, 0, 8);
     ///
-    /// assert_eq!(x.try_get_normalized(), Some(Vec3::new(1, 0, 0)));
-    /// assert_eq!(zero.try_get_normalized(), None);
+    /// assert_eq!(position.move_towards(target, 4.into()), Vec3::new(0, 0, 4));
+    /// assert_eq!(position.move_towards(target, 15.into()), target);
     /// ```
     #[must_use]
-    pub fn try_get_normalized(&self) -> Option<Self> {
-        let len = self.magnitude();
+    pub fn move_towards(&self, target: Self, max_delta: T) -> Self {
+        let offset = target - *self;
+        let distance = offset.len();
 
-        if len == SignedFractional::ZERO {
-            Self::considers_this_unlikely_to_happen();
-            return None;
+        if distance <= max_delta || distance == T::ZERO {
+            return target;
         }
 
-        Some(Self {
-            x: self.x / len,
-            y: self.y / len,
-            z: self.z / len,
-        })
+        *self + offset * (max_delta / distance)
+    }
+}
+
+impl Default for Vec3 {
+    fn default() -> Self {
+        Self::ZERO
     }
 }
 
@@ -156,140 +988,213 @@ impl From<(SignedFractional, SignedFractional, SignedFractional)> for Vec3 {
     }
 }
 
-impl From<Vec3> for (SignedFractional, SignedFractional, SignedFractional) {
-    fn from(n: Vec3) -> Self {
+impl<T> From<Vec3<T>> for (T, T, T) {
+    fn from(n: Vec3<T>) -> Self {
         (n.x, n.y, n.z)
     }
 }
 
-impl Neg for Vec3 {
+impl<T: Display> Display for Vec3<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(precision) = f.precision() {
+            write!(
+                f,
+                "({:.*}, {:.*}, {:.*})",
+                precision, self.x, precision, self.y, precision, self.z
+            )
+        } else {
+            write!(f, "({}, {}, {})", self.x, self.y, self.z)
+        }
+    }
+}
+
+/// Parses a vector written as comma separated coordinates, optionally wrapped in parentheses
+///
+/// # Examples
+/// ```
+/// # use skala_engine_numerics::vector::Vec3;
+/// # use skala_engine_numerics::SignedFractional;
+/// let vector: Vec3 = "1.5,2,3".parse().unwrap();
+///
+/// assert_eq!(vector, Vec3::new(SignedFractional::from_num(1.5), 2, 3));
+/// ```
+impl FromStr for Vec3 {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse::parse_components(s)?;
+
+        Ok(Self { x, y, z })
+    }
+}
+
+impl<T: FixedSigned> Neg for Vec3<T> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Self {
-            x: -self.x,
-            y: -self.y,
-            z: -self.z,
-        }
+        (-Vector::from(self)).into()
     }
 }
 
-impl Add for Vec3 {
+impl<T: FixedSigned> Add for Vec3<T> {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
+        (Vector::from(self) + Vector::from(rhs)).into()
     }
 }
 
-impl Add<(SignedFractional, SignedFractional, SignedFractional)> for Vec3 {
+impl<T: FixedSigned> Add<(T, T, T)> for Vec3<T> {
     type Output = Self;
 
-    fn add(self, rhs: (SignedFractional, SignedFractional, SignedFractional)) -> Self::Output {
-        self + Into::<Self>::into(rhs)
+    fn add(self, rhs: (T, T, T)) -> Self::Output {
+        self + Self {
+            x: rhs.0,
+            y: rhs.1,
+            z: rhs.2,
+        }
     }
 }
 
-impl AddAssign for Vec3 {
+impl<T: FixedSigned> AddAssign for Vec3<T> {
     fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
+        *self = *self + rhs;
     }
 }
 
-impl AddAssign<(SignedFractional, SignedFractional, SignedFractional)> for Vec3 {
-    fn add_assign(&mut self, rhs: (SignedFractional, SignedFractional, SignedFractional)) {
+impl<T: FixedSigned> AddAssign<(T, T, T)> for Vec3<T> {
+    fn add_assign(&mut self, rhs: (T, T, T)) {
         self.x += rhs.0;
         self.y += rhs.1;
         self.z += rhs.2;
     }
 }
 
-impl Sub for Vec3 {
-    type Output = Vec3;
+impl<T: FixedSigned> Sub for Vec3<T> {
+    type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            x: self.x - rhs.x,
-            y: self.y - rhs.y,
-            z: self.z - rhs.z,
-        }
+        (Vector::from(self) - Vector::from(rhs)).into()
     }
 }
 
-impl Sub<(SignedFractional, SignedFractional, SignedFractional)> for Vec3 {
+impl<T: FixedSigned> Sub<(T, T, T)> for Vec3<T> {
     type Output = Self;
 
-    fn sub(self, rhs: (SignedFractional, SignedFractional, SignedFractional)) -> Self::Output {
-        self - Into::<Self>::into(rhs)
+    fn sub(self, rhs: (T, T, T)) -> Self::Output {
+        self - Self {
+            x: rhs.0,
+            y: rhs.1,
+            z: rhs.2,
+        }
     }
 }
 
-impl SubAssign for Vec3 {
+impl<T: FixedSigned> SubAssign for Vec3<T> {
     fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
+        *self = *self - rhs;
     }
 }
 
-impl SubAssign<(SignedFractional, SignedFractional, SignedFractional)> for Vec3 {
-    fn sub_assign(&mut self, rhs: (SignedFractional, SignedFractional, SignedFractional)) {
+impl<T: FixedSigned> SubAssign<(T, T, T)> for Vec3<T> {
+    fn sub_assign(&mut self, rhs: (T, T, T)) {
         self.x -= rhs.0;
         self.y -= rhs.1;
         self.z -= rhs.2;
     }
 }
 
-impl Mul<SignedFractional> for Vec3 {
+impl<T: FixedSigned> Mul<T> for Vec3<T> {
     type Output = Self;
 
-    fn mul(self, rhs: SignedFractional) -> Self::Output {
-        Self {
-            x: self.x * rhs,
-            y: self.y * rhs,
-            z: self.z * rhs,
-        }
+    fn mul(self, rhs: T) -> Self::Output {
+        (Vector::from(self) * rhs).into()
     }
 }
 
-impl MulAssign<SignedFractional> for Vec3 {
-    fn mul_assign(&mut self, rhs: SignedFractional) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
+impl<T: FixedSigned> MulAssign<T> for Vec3<T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
     }
 }
 
-impl Div<SignedFractional> for Vec3 {
+impl<T: FixedSigned> Div<T> for Vec3<T> {
     type Output = Self;
 
-    fn div(self, rhs: SignedFractional) -> Self::Output {
-        Self {
-            x: self.x / rhs,
-            y: self.y / rhs,
-            z: self.z / rhs,
-        }
+    fn div(self, rhs: T) -> Self::Output {
+        (Vector::from(self) / rhs).into()
+    }
+}
+
+impl<T: FixedSigned> DivAssign<T> for Vec3<T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: FixedSigned> Neg for &Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+impl<T: FixedSigned> Add<&Vec3<T>> for &Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, rhs: &Vec3<T>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<T: FixedSigned> Add<&Vec3<T>> for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: &Vec3<T>) -> Self::Output {
+        self + *rhs
+    }
+}
+
+impl<T: FixedSigned> Sub<&Vec3<T>> for &Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, rhs: &Vec3<T>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<T: FixedSigned> Sub<&Vec3<T>> for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: &Vec3<T>) -> Self::Output {
+        self - *rhs
+    }
+}
+
+impl<T: FixedSigned> Mul<T> for &Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        *self * rhs
     }
 }
 
-impl DivAssign<SignedFractional> for Vec3 {
-    fn div_assign(&mut self, rhs: SignedFractional) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
+impl<T: FixedSigned> Div<T> for &Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        *self / rhs
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::vector::Vec3;
+    use crate::vector::{NormalizeError, ParseVectorError, Vec2, Vec3};
     use crate::SignedFractional;
+    use fixed::traits::FixedSigned;
+    use fixed::types::{I32F32, I48F16};
+    use fixed_sqrt::FixedSqrt;
 
     #[test]
     // Tests that derive(Eq) continues to be correct
@@ -318,6 +1223,17 @@ mod test {
         assert_eq!(x, y);
     }
 
+    #[test]
+    fn default_backing_is_inferred() {
+        // Call sites naming no backing type have to keep compiling with the default one
+        let zero = Vec3::default();
+        let x = Vec3::from((1.into(), 2.into(), 3.into()));
+
+        assert_eq!(zero.len(), 0);
+        assert_eq!(x.dot(Vec3::ONE), 6);
+        assert_eq!(x - (1.into(), 1.into(), 1.into()), Vec3::new(0, 1, 2));
+    }
+
     #[test]
     fn addition() {
         let x = Vec3::new(2, 3, 9);
@@ -331,9 +1247,9 @@ mod test {
         let x = Vec3::new(3, 4, 12);
         let y = Vec3::new(2, 4, 4);
 
-        assert_eq!(x.magnitude_pow2(), 169);
-        assert_eq!(x.magnitude(), 13);
-        assert_eq!(y.magnitude(), 6);
+        assert_eq!(x.len_pow2(), 169);
+        assert_eq!(x.len(), 13);
+        assert_eq!(y.len(), 6);
     }
 
     #[test]
@@ -357,7 +1273,513 @@ mod test {
         let x = Vec3::new(4, 4, 4);
         let wrong = Vec3::ZERO;
 
-        assert_eq!(x.get_normalized().magnitude(), 1);
+        assert_eq!(x.get_normalized().len(), 1);
         assert_eq!(wrong.try_get_normalized(), None);
     }
+
+    #[test]
+    fn approximate_equality() {
+        let x = Vec3::new(3, 7, 2).get_normalized();
+        let y = Vec3::new(6, 14, 4).get_normalized();
+        let nudged = x + Vec3::new(0, 0, SignedFractional::DELTA);
+
+        assert!(x.approx_eq(y, SignedFractional::from_num(0.000_001)));
+        assert!(x.approx_eq(nudged, SignedFractional::DELTA));
+        assert!(!x.approx_eq(nudged, 0.into()));
+        assert!(!x.approx_eq(Vec3::Z, SignedFractional::from_num(0.1)));
+    }
+
+    #[test]
+    fn reductions() {
+        let x = Vec3::new(3, -4, 5);
+        // Close to the limit of what the product can represent
+        let large = Vec3::new(1_000, 1_000, 2_000);
+
+        assert_eq!(x.element_sum(), 4);
+        assert_eq!(x.element_product(), -60);
+        assert_eq!(large.element_sum(), 4_000);
+        assert_eq!(large.element_product(), 2_000_000_000);
+    }
+
+    #[test]
+    fn mapping() {
+        let x = Vec3::new(3, -4, 5);
+
+        assert_eq!(x.map(|c| c * SignedFractional::from_num(2)), x * 2.into());
+        assert_eq!(x.map(|c| c), x);
+    }
+
+    #[test]
+    fn absolute_value_and_sign() {
+        let x = Vec3::new(-5, 0, 7);
+
+        assert_eq!(x.abs(), Vec3::new(5, 0, 7));
+        assert_eq!(x.signum(), Vec3::new(-1, 0, 1));
+    }
+
+    #[test]
+    fn rounding() {
+        let x = Vec3::new(
+            SignedFractional::from_num(2.75),
+            SignedFractional::from_num(-2.25),
+            SignedFractional::from_num(-0.5),
+        );
+
+        assert_eq!(x.floor(), Vec3::new(2, -3, -1));
+        assert_eq!(x.ceil(), Vec3::new(3, -2, 0));
+        assert_eq!(x.round(), Vec3::new(3, -2, -1));
+
+        // Snapping to a grid with cells of size 4
+        let cell = SignedFractional::from_num(4);
+        let position = Vec3::new(9, -3, SignedFractional::from_num(6.5));
+        assert_eq!((position / cell).round() * cell, Vec3::new(8, -4, 8));
+    }
+
+    #[test]
+    fn component_min_max() {
+        let x = Vec3::new(2, 9, 4);
+        let y = Vec3::new(5, -1, 4);
+
+        assert_eq!(x.min(y), Vec3::new(2, -1, 4));
+        assert_eq!(x.max(y), Vec3::new(5, 9, 4));
+    }
+
+    #[test]
+    fn component_clamp() {
+        let lo = Vec3::new(0, 0, 0);
+        let hi = Vec3::new(10, 10, 10);
+
+        assert_eq!(Vec3::new(5, -3, 12).clamp(lo, hi), Vec3::new(5, 0, 10));
+    }
+
+    #[test]
+    fn midpoint() {
+        let x = Vec3::new(0, 0, 0);
+        let y = Vec3::new(4, 6, -2);
+
+        assert_eq!(x.midpoint(y), Vec3::new(2, 3, -1));
+        assert_eq!(y.midpoint(x), Vec3::new(2, 3, -1));
+    }
+
+    #[test]
+    fn magnitude_clamping() {
+        let short = Vec3::new(2, 4, 4);
+        let long = Vec3::new(4, 8, 8);
+
+        assert_eq!(short.clamp_magnitude(10.into()), short);
+        assert_eq!(long.clamp_magnitude(6.into()), short);
+        assert_eq!(Vec3::ZERO.clamp_magnitude(5.into()), Vec3::ZERO);
+    }
+
+    #[test]
+    fn moving_towards() {
+        let x = Vec3::new(1, 1, 1);
+        let target = Vec3::new(3, 5, 5);
+
+        assert_eq!(x.move_towards(target, 3.into()), Vec3::new(2, 3, 3));
+        assert_eq!(x.move_towards(target, 7.into()), target);
+        assert_eq!(x.move_towards(target, 0.into()), x);
+        assert_eq!(target.move_towards(target, 1.into()), target);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Vec3::ONE, Vec3::new(1, 1, 1));
+        assert_eq!(Vec3::X, Vec3::new(1, 0, 0));
+        assert_eq!(Vec3::Y, Vec3::new(0, 1, 0));
+        assert_eq!(Vec3::Z, Vec3::new(0, 0, 1));
+        assert_eq!(Vec3::X + Vec3::Y + Vec3::Z, Vec3::ONE);
+    }
+
+    #[test]
+    fn checked_arithmetic_near_bounds() {
+        let almost_max = SignedFractional::MAX - SignedFractional::ONE;
+        let almost_min = SignedFractional::MIN + SignedFractional::ONE;
+        let two = SignedFractional::from_num(2);
+
+        let x = Vec3::new(almost_max, 0, 0);
+        assert_eq!(
+            x.checked_add(Vec3::X),
+            Some(Vec3::new(SignedFractional::MAX, 0, 0))
+        );
+        assert_eq!(x.checked_add(Vec3::X * two), None);
+
+        let y = Vec3::new(0, almost_min, 0);
+        assert_eq!(
+            y.checked_sub(Vec3::Y),
+            Some(Vec3::new(0, SignedFractional::MIN, 0))
+        );
+        assert_eq!(y.checked_sub(Vec3::Y * two), None);
+
+        let z = Vec3::new(0, 0, almost_max);
+        assert_eq!(
+            z.checked_mul(SignedFractional::from_num(0.5)),
+            Some(Vec3::new(0, 0, almost_max / two))
+        );
+        assert_eq!(z.checked_mul(two), None);
+        assert_eq!(z.checked_div(two), Some(Vec3::new(0, 0, almost_max / two)));
+        assert_eq!(z.checked_div(SignedFractional::from_num(0.5)), None);
+        assert_eq!(z.checked_div(SignedFractional::ZERO), None);
+    }
+
+    #[test]
+    fn magnitude_of_large_vectors() {
+        let epsilon = SignedFractional::from_num(0.01);
+
+        let x = Vec3::new(30_000, 40_000, 0);
+        assert_eq!(x.len(), 50_000);
+
+        let y = Vec3::new(-60_000, 0, 80_000);
+        assert_eq!(y.len(), 100_000);
+
+        let z = Vec3::new(30_000, 30_000, 30_000);
+        assert!((z.len() - SignedFractional::from_num(51_961.524_227)).abs() < epsilon);
+    }
+
+    #[test]
+    fn truncating() {
+        let x = Vec3::new(7, -8, 9);
+
+        assert_eq!(x.truncate(), Vec2::new(7.into(), (-8).into()));
+        assert_eq!(x.truncate().extend(9.into()), x);
+    }
+
+    #[test]
+    fn normalizing_or_zero() {
+        let mut x = Vec3::new(0, -5, 0);
+        let mut zero = Vec3::ZERO;
+
+        assert_eq!(x.normalize_or_zero(), -Vec3::Y);
+        assert_eq!(zero.normalize_or_zero(), Vec3::ZERO);
+
+        x.normalize_or_zero_mut();
+        zero.normalize_or_zero_mut();
+
+        assert_eq!(x, -Vec3::Y);
+        assert_eq!(zero, Vec3::ZERO);
+    }
+
+    #[test]
+    fn reference_operators() {
+        let x = Vec3::new(1, 2, 3);
+        let y = Vec3::new(4, 6, 8);
+        let (x_ref, y_ref) = (&x, &y);
+        let three = SignedFractional::from_num(3);
+
+        assert_eq!(x_ref + y_ref, x + y);
+        assert_eq!(y_ref - x_ref, y - x);
+        assert_eq!(y - x_ref, y - x);
+        assert_eq!(-x_ref, -x);
+        assert_eq!(x_ref * three, x * three);
+        assert_eq!(y_ref / three, y / three);
+
+        let points = [x, y, x];
+        let sum = points.iter().fold(Vec3::ZERO, |sum, point| sum + point);
+
+        assert_eq!(sum, Vec3::new(6, 10, 14));
+    }
+
+    #[test]
+    fn try_normalizing() {
+        let x = Vec3::new(-2, 0, 0);
+
+        assert_eq!(x.try_normalize(), Ok(-Vec3::X));
+        assert_eq!(Vec3::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+    }
+
+    #[test]
+    fn manhattan() {
+        let x = Vec3::ZERO;
+        let y = Vec3::new(3, 4, 0);
+
+        assert_eq!(x.manhattan_distance(y), 7);
+        assert_eq!(y.manhattan_distance(x), 7);
+        assert_eq!((y - x).len(), 5);
+        assert_eq!(x.manhattan_distance(Vec3::new(-1, 2, -3)), 6);
+    }
+
+    #[test]
+    fn chebyshev() {
+        let x = Vec3::ZERO;
+        let y = Vec3::new(3, 4, 0);
+
+        assert_eq!(x.chebyshev_distance(y), 4);
+        assert_eq!(y.chebyshev_distance(x), 4);
+        assert_eq!(x.chebyshev_distance(Vec3::new(-1, 2, -5)), 5);
+    }
+
+    #[test]
+    fn dot_product() {
+        let x = Vec3::new(2, 3, -1);
+
+        assert_eq!(x.dot(x), x.len_pow2());
+        assert_eq!(x.dot(Vec3::new(1, 0, 2)), 0);
+        assert_eq!(Vec3::X.dot(-Vec3::X), -1);
+    }
+
+    #[test]
+    fn cross_product() {
+        let x = Vec3::new(2, 3, -1);
+        let y = Vec3::new(-4, 1, 5);
+        let cross = x.cross(y);
+
+        assert_eq!(cross, Vec3::new(16, -6, 14));
+        assert_eq!(cross.dot(x), 0);
+        assert_eq!(cross.dot(y), 0);
+        assert_eq!(Vec3::Z.cross(Vec3::X), Vec3::Y);
+        assert_eq!(x.cross(x), Vec3::ZERO);
+    }
+
+    #[test]
+    fn slerp_midpoints() {
+        let half_sqrt_2 = SignedFractional::SQRT_2 / SignedFractional::from_num(2);
+        let epsilon = SignedFractional::from_num(0.000_001);
+
+        let midpoint = Vec3::X.slerp_midpoint(Vec3::Y);
+        assert!(midpoint.approx_eq(Vec3::new(half_sqrt_2, half_sqrt_2, 0), epsilon));
+
+        assert_eq!(Vec3::Z.slerp_midpoint(Vec3::Z), Vec3::Z);
+
+        let opposite = Vec3::new(1, 2, 3);
+        let perpendicular = opposite.slerp_midpoint(-opposite);
+        assert!(perpendicular.dot(opposite).abs() < epsilon);
+        assert!((perpendicular.len() - SignedFractional::ONE).abs() < epsilon);
+    }
+
+    #[test]
+    fn orthonormal_bases() {
+        let epsilon = SignedFractional::from_num(0.000_01);
+        let vectors = [
+            Vec3::X,
+            Vec3::new(0, -3, 0),
+            Vec3::new(1, 2, 3),
+            Vec3::new(-40, 0, 10),
+            Vec3::new(
+                SignedFractional::from_num(0.001),
+                7,
+                SignedFractional::from_num(-0.002),
+            ),
+        ];
+
+        for vector in vectors {
+            let (tangent, bitangent) = vector.any_orthonormal_basis();
+
+            assert!(tangent.dot(vector).abs() < epsilon);
+            assert!(bitangent.dot(vector).abs() < epsilon);
+            assert!(tangent.dot(bitangent).abs() < epsilon);
+            assert!((tangent.len() - SignedFractional::ONE).abs() < epsilon);
+            assert!((bitangent.len() - SignedFractional::ONE).abs() < epsilon);
+            assert!(tangent
+                .cross(bitangent)
+                .approx_eq(vector.get_normalized(), epsilon));
+        }
+    }
+
+    #[test]
+    fn scalar_triple_product() {
+        assert_eq!(Vec3::X.scalar_triple(Vec3::Y, Vec3::Z), 1);
+        assert_eq!(Vec3::Y.scalar_triple(Vec3::Z, Vec3::X), 1);
+        assert_eq!(Vec3::Y.scalar_triple(Vec3::X, Vec3::Z), -1);
+
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::new(-2, 0, 1);
+        let coplanar = a * SignedFractional::from_num(2) - b;
+
+        assert_eq!(a.scalar_triple(b, coplanar), 0);
+    }
+
+    #[test]
+    fn display() {
+        let x = Vec3::new(1, SignedFractional::from_num(-2.3125), 3);
+
+        assert_eq!(x.to_string(), "(1, -2.3125, 3)");
+        assert_eq!(format!("{x:.1}"), "(1.0, -2.3, 3.0)");
+    }
+
+    #[test]
+    fn parsing() {
+        let expected = Vec3::new(SignedFractional::from_num(1.5), 2, -3);
+
+        assert_eq!("1.5,2,-3".parse::<Vec3>(), Ok(expected));
+        assert_eq!("(1.5, 2, -3)".parse::<Vec3>(), Ok(expected));
+        assert_eq!(expected.to_string().parse::<Vec3>(), Ok(expected));
+    }
+
+    #[test]
+    fn parsing_malformed() {
+        assert_eq!(
+            "1,2".parse::<Vec3>(),
+            Err(ParseVectorError::ComponentCount {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "1,2,3,4".parse::<Vec3>(),
+            Err(ParseVectorError::ComponentCount {
+                expected: 3,
+                found: 4
+            })
+        );
+        assert!(matches!(
+            "1,2,x".parse::<Vec3>(),
+            Err(ParseVectorError::InvalidComponent { index: 2, .. })
+        ));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_magnitude_names() {
+        let x = Vec3::new(2, -3, 6);
+
+        assert_eq!(x.magnitude_pow2(), x.len_pow2());
+        assert_eq!(x.magnitude(), x.len());
+    }
+
+    #[test]
+    fn smoothstep() {
+        let start = Vec3::new(-2, 3, 1);
+        let end = Vec3::new(6, -5, 9);
+        let half = SignedFractional::from_num(0.5);
+
+        assert_eq!(start.smoothstep(end, 0.into()), start);
+        assert_eq!(start.smoothstep(end, 1.into()), end);
+        assert_eq!(start.smoothstep(end, half), start.lerp(end, half));
+        assert_eq!(start.smoothstep(end, half), start.midpoint(end));
+        assert_eq!(start.smoothstep(end, (-1).into()), start);
+    }
+
+    #[test]
+    fn nlerp() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let from = Vec3::new(2, 3, 6).get_normalized();
+        let to = Vec3::new(-6, 2, 3).get_normalized();
+
+        assert!(from.nlerp(to, 0.into()).approx_eq(from, epsilon));
+        assert!(from.nlerp(to, 1.into()).approx_eq(to, epsilon));
+
+        for step in 0..=8 {
+            let t = SignedFractional::from_num(step) / SignedFractional::from_num(8);
+            let blended = from.nlerp(to, t);
+
+            assert!((blended.len() - SignedFractional::ONE).abs() < epsilon);
+        }
+    }
+
+    #[test]
+    fn nlerp_opposite_directions() {
+        let half = SignedFractional::from_num(0.5);
+        let nearly_opposite = Vec3::new(-1, SignedFractional::DELTA, SignedFractional::DELTA);
+
+        assert_eq!(Vec3::Y.nlerp(-Vec3::Y, half), -Vec3::Y);
+        assert_eq!(
+            Vec3::Y.nlerp(-Vec3::Y, SignedFractional::from_num(0.25)),
+            Vec3::Y
+        );
+
+        let blended = Vec3::X.nlerp(nearly_opposite, half);
+        assert!((blended.len() - SignedFractional::ONE).abs() < SignedFractional::from_num(0.001));
+    }
+
+    #[test]
+    fn reciprocal() {
+        let scale = Vec3::new(4, SignedFractional::from_num(-0.5), 1);
+
+        assert_eq!(
+            scale.recip(),
+            Vec3::new(SignedFractional::from_num(0.25), -2, 1)
+        );
+        assert_eq!(scale.try_recip(), Some(scale.recip()));
+        assert_eq!(Vec3::new(1, 2, 0).try_recip(), None);
+        assert_eq!(Vec3::ZERO.try_recip(), None);
+    }
+
+    #[test]
+    fn dot_with_tuple() {
+        let x = Vec3::new(3, -2, 5);
+        let y = (SignedFractional::from_num(1.5), 4.into(), (-1).into());
+
+        assert_eq!(x.dot_tuple(y), x.dot(y.into()));
+        assert_eq!(x.dot_tuple(y), SignedFractional::from_num(-8.5));
+    }
+
+    #[test]
+    fn spherical_round_trip() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+
+        for point in [
+            Vec3::new(2, 3, 6),
+            Vec3::new(-1, 2, -2),
+            Vec3::new(4, -4, 7),
+            Vec3::new(-3, -1, 5),
+        ] {
+            let (radius, theta, phi) = point.to_spherical();
+
+            assert!(theta >= SignedFractional::ZERO && theta <= SignedFractional::PI);
+            assert!(Vec3::from_spherical(radius, theta, phi).approx_eq(point, epsilon));
+        }
+
+        assert_eq!(Vec3::new(2, 3, 6).to_spherical().0, 7);
+    }
+
+    #[test]
+    fn spherical_poles() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let pole = Vec3::new(0, 5, 0);
+
+        let (radius, theta, phi) = pole.to_spherical();
+        assert_eq!(radius, 5);
+        assert!(theta.abs() < epsilon);
+        assert_eq!(
+            phi, 0,
+            "Azimuth at the pole is undefined and reported as zero"
+        );
+        assert!(Vec3::from_spherical(radius, theta, phi).approx_eq(pole, epsilon));
+
+        // Any azimuth reaches the pole
+        assert!(Vec3::from_spherical(5.into(), 0.into(), 2.into()).approx_eq(pole, epsilon));
+
+        assert_eq!(Vec3::ZERO.to_spherical(), (0.into(), 0.into(), 0.into()));
+    }
+
+    fn arithmetic_with_backing<T: FixedSigned + FixedSqrt>() {
+        let vec3 = |x: f64, y: f64, z: f64| Vec3 {
+            x: T::from_num(x),
+            y: T::from_num(y),
+            z: T::from_num(z),
+        };
+        let x = vec3(2.0, -3.0, 6.0);
+        let unit_x = vec3(1.0, 0.0, 0.0);
+        let unit_y = vec3(0.0, 1.0, 0.0);
+        let epsilon = T::from_num(0.001);
+
+        assert_eq!(x.len(), T::from_num(7));
+        assert_eq!(x.dot(unit_x), T::from_num(2));
+        assert_eq!(unit_x.cross(unit_y), vec3(0.0, 0.0, 1.0));
+        assert_eq!(x + -x, vec3(0.0, 0.0, 0.0));
+        assert_eq!(
+            x.midpoint(-x + unit_x * T::from_num(4)),
+            vec3(2.0, 0.0, 0.0)
+        );
+        assert!(x.get_normalized().approx_eq(x / T::from_num(7), epsilon));
+
+        let (tangent, bitangent) = x.any_orthonormal_basis();
+        assert!(tangent.dot(x).abs() < epsilon);
+        assert!(bitangent.dot(x).abs() < epsilon);
+    }
+
+    #[test]
+    fn generic_backing_types() {
+        arithmetic_with_backing::<I32F32>();
+        arithmetic_with_backing::<I48F16>();
+
+        // Out of range for I32F32 but not for I48F16
+        let far = Vec3 {
+            x: I48F16::from_num(2_000_000_000_i64),
+            y: I48F16::from_num(4_000_000_000_i64),
+            z: I48F16::from_num(4_000_000_000_i64),
+        };
+
+        assert_eq!(far.len(), I48F16::from_num(6_000_000_000_i64));
+    }
 }
@@ -0,0 +1,491 @@
+use crate::vector::{NormalizeError, Vec2, Vec3};
+use crate::SignedFractional;
+use fixed::traits::FixedSigned;
+use fixed_sqrt::FixedSqrt;
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+/// A vector with `N` components
+///
+/// Implements the arithmetic shared by all vectors. [`Vec2`] and [`Vec3`] convert to and from [`Vector<2>`] and
+/// [`Vector<3>`] and delegate their shared arithmetic to it, dimensions without a dedicated type use it directly.
+///
+/// Backed by [`SignedFractional`] unless another signed fixed point type is picked with `T`, see [`Vec2`].
+#[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
+pub struct Vector<const N: usize, T = SignedFractional> {
+    /// The coordinates of the vector in order
+    pub components: [T; N],
+}
+
+/// A 4d vector, usually holding homogeneous coordinates
+pub type Vec4 = Vector<4>;
+
+impl<const N: usize> Vector<N> {
+    /// A [`Vector`] with all of its components set to zero
+    pub const ZERO: Self = Self {
+        components: [SignedFractional::ZERO; N],
+    };
+
+    /// A [`Vector`] with all of its components set to one
+    pub const ONE: Self = Self {
+        components: [SignedFractional::ONE; N],
+    };
+
+    /// Creates a new [`Vector`] from its components
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([1.into(), 2.into(), 3.into(), 4.into()]);
+    ///
+    /// assert_eq!(x[3], 4);
+    /// ```
+    #[must_use]
+    pub const fn new(components: [SignedFractional; N]) -> Self {
+        Self { components }
+    }
+}
+
+impl<const N: usize, T: FixedSigned + FixedSqrt> Vector<N, T> {
+    /// Returns the magnitude of this [`Vector`] raised to the power of two
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([1.into(), 1.into(), 1.into(), 1.into()]);
+    ///
+    /// assert_eq!(x.len_pow2(), 4);
+    /// ```
+    #[must_use]
+    pub fn len_pow2(&self) -> T {
+        self.dot(*self)
+    }
+
+    /// Returns the magnitude of this [`Vector`] raised to the power of two or `None` if it can't be represented
+    pub(crate) fn checked_len_pow2(&self) -> Option<T> {
+        self.components.iter().try_fold(T::ZERO, |sum, component| {
+            sum.checked_add(component.checked_mul(*component)?)
+        })
+    }
+
+    /// Returns the magnitude of this [`Vector`]
+    ///
+    /// Works even when [`len_pow2`](Self::len_pow2) would overflow as long as the magnitude itself fits into the
+    /// backing type.
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([1.into(), 3.into(), 3.into(), 9.into()]);
+    ///
+    /// assert_eq!(x.len(), 10);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> T {
+        if let Some(len_pow2) = self.checked_len_pow2() {
+            return len_pow2.sqrt();
+        }
+
+        Self::considers_this_unlikely_to_happen();
+
+        // Scale the vector down by its largest component so the squares can't overflow
+        let largest = self
+            .components
+            .iter()
+            .fold(T::ZERO, |largest, component| largest.max(component.abs()));
+        let scaled = *self / largest;
+
+        scaled.len_pow2().sqrt() * largest
+    }
+
+    /// Returns the dot product of this [`Vector`] and `other`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([1.into(), 2.into(), 3.into(), 4.into()]);
+    /// let y = Vec4::new([4.into(), (-3).into(), 2.into(), 1.into()]);
+    ///
+    /// assert_eq!(x.dot(y), 8);
+    /// ```
+    #[must_use]
+    pub fn dot(&self, other: Self) -> T {
+        self.components
+            .iter()
+            .zip(other.components)
+            .fold(T::ZERO, |sum, (a, b)| sum + *a * b)
+    }
+
+    /// Sets the magnitude of this [`Vector`] to one
+    ///
+    /// # Panics
+    /// If this [`Vector`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let mut x = Vec4::new([0.into(), 0.into(), 0.into(), 5.into()]);
+    /// x.normalize();
+    ///
+    /// assert_eq!(x, Vec4::new([0.into(), 0.into(), 0.into(), 1.into()]));
+    /// ```
+    pub fn normalize(&mut self) {
+        *self /= self.len();
+    }
+
+    /// Creates a [`Vector`] with magnitude equal to one and direction equal to this [`Vector`]
+    ///
+    /// # Panics
+    /// If this [`Vector`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([0.into(), 3.into(), 0.into(), 4.into()]);
+    ///
+    /// assert_eq!(x.get_normalized().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn get_normalized(&self) -> Self {
+        *self / self.len()
+    }
+
+    /// Creates a [`Vector`] with magnitude equal to one and direction equal to this [`Vector`] or `None` if this
+    /// [`Vector`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// assert_eq!(Vec4::ZERO.try_get_normalized(), None);
+    /// ```
+    #[must_use]
+    pub fn try_get_normalized(&self) -> Option<Self> {
+        self.try_normalize().ok()
+    }
+
+    /// Creates a [`Vector`] with magnitude equal to one and direction equal to this [`Vector`]
+    ///
+    /// # Errors
+    /// [`NormalizeError::ZeroVector`] if this [`Vector`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::{NormalizeError, Vec4};
+    /// assert_eq!(Vec4::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+    /// ```
+    pub fn try_normalize(&self) -> Result<Self, NormalizeError> {
+        let len = self.len();
+
+        if len == T::ZERO {
+            Self::considers_this_unlikely_to_happen();
+            return Err(NormalizeError::ZeroVector);
+        }
+
+        Ok(*self / len)
+    }
+
+    /// Creates a [`Vector`] with magnitude equal to one and direction equal to this [`Vector`] or a zero vector if
+    /// this [`Vector`] is a zero vector
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// assert_eq!(Vec4::ZERO.normalize_or_zero(), Vec4::ZERO);
+    /// ```
+    #[must_use]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.try_get_normalized().unwrap_or(Self {
+            components: [T::ZERO; N],
+        })
+    }
+
+    /// Checks whether every component of this [`Vector`] is within `epsilon` of the matching component of `other`
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// # use skala_engine_numerics::SignedFractional;
+    /// let x = Vec4::new([1.into(), 2.into(), 3.into(), 4.into()]);
+    /// let y = x + Vec4::ONE * SignedFractional::from_num(0.001);
+    ///
+    /// assert!(x.approx_eq(y, SignedFractional::from_num(0.01)));
+    /// ```
+    #[must_use]
+    pub fn approx_eq(&self, other: Self, epsilon: T) -> bool {
+        self.components
+            .iter()
+            .zip(other.components)
+            .all(|(a, b)| (*a - b).abs() <= epsilon)
+    }
+
+    #[cold]
+    fn considers_this_unlikely_to_happen() {}
+}
+
+impl<const N: usize, T: FixedSigned> Vector<N, T> {
+    /// Creates a [`Vector`] by applying `f` to every component of this [`Vector`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use skala_engine_numerics::vector::Vec4;
+    /// let x = Vec4::new([1.into(), (-2).into(), 3.into(), (-4).into()]);
+    ///
+    /// assert_eq!(x.map(|component| component.abs()), Vec4::new([1.into(), 2.into(), 3.into(), 4.into()]));
+    /// ```
+    #[must_use]
+    pub fn map<F: Fn(T) -> T>(&self, f: F) -> Self {
+        Self {
+            components: self.components.map(f),
+        }
+    }
+
+    /// Combines the matching components of this [`Vector`] and `other` using `f`
+    fn zip_map<F: Fn(T, T) -> T>(&self, other: Self, f: F) -> Self {
+        let mut components = self.components;
+
+        for (component, other) in components.iter_mut().zip(other.components) {
+            *component = f(*component, other);
+        }
+
+        Self { components }
+    }
+}
+
+impl<const N: usize> Default for Vector<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<[SignedFractional; N]> for Vector<N> {
+    fn from(n: [SignedFractional; N]) -> Self {
+        Self { components: n }
+    }
+}
+
+impl<const N: usize> From<Vector<N>> for [SignedFractional; N] {
+    fn from(n: Vector<N>) -> Self {
+        n.components
+    }
+}
+
+impl<T> From<Vec2<T>> for Vector<2, T> {
+    fn from(n: Vec2<T>) -> Self {
+        Self {
+            components: [n.x, n.y],
+        }
+    }
+}
+
+impl<T> From<Vector<2, T>> for Vec2<T> {
+    fn from(n: Vector<2, T>) -> Self {
+        let [x, y] = n.components;
+
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<Vec3<T>> for Vector<3, T> {
+    fn from(n: Vec3<T>) -> Self {
+        Self {
+            components: [n.x, n.y, n.z],
+        }
+    }
+}
+
+impl<T> From<Vector<3, T>> for Vec3<T> {
+    fn from(n: Vector<3, T>) -> Self {
+        let [x, y, z] = n.components;
+
+        Vec3 { x, y, z }
+    }
+}
+
+impl<const N: usize, T: Display> Display for Vector<N, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            if let Some(precision) = f.precision() {
+                write!(f, "{component:.precision$}")?;
+            } else {
+                write!(f, "{component}")?;
+            }
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Vector<N, T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.components[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for Vector<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+impl<const N: usize, T: FixedSigned> Neg for Vector<N, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|component| -component)
+    }
+}
+
+impl<const N: usize, T: FixedSigned> Add for Vector<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize, T: FixedSigned> AddAssign for Vector<N, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, T: FixedSigned> Sub for Vector<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize, T: FixedSigned> SubAssign for Vector<N, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, T: FixedSigned> Mul<T> for Vector<N, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|component| component * rhs)
+    }
+}
+
+impl<const N: usize, T: FixedSigned> MulAssign<T> for Vector<N, T> {
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: usize, T: FixedSigned> Div<T> for Vector<N, T> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|component| component / rhs)
+    }
+}
+
+impl<const N: usize, T: FixedSigned> DivAssign<T> for Vector<N, T> {
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{NormalizeError, Vec2, Vec3, Vec4, Vector};
+    use crate::SignedFractional;
+    use fixed::types::I48F16;
+
+    #[test]
+    fn matches_vec2() {
+        let a = Vec2::new(3.into(), (-4).into());
+        let b = Vec2::new(SignedFractional::from_num(0.5), 2.into());
+        let (x, y) = (Vector::from(a), Vector::from(b));
+
+        assert_eq!(x.len(), a.len());
+        assert_eq!(x.len_pow2(), a.len_pow2());
+        assert_eq!(x.dot(y), a.dot(b));
+        assert_eq!(Vec2::from(x + y), a + b);
+        assert_eq!(Vec2::from(x - y), a - b);
+        assert_eq!(Vec2::from(-x), -a);
+        assert_eq!(Vec2::from(x.get_normalized()), a.get_normalized());
+    }
+
+    #[test]
+    fn matches_vec3() {
+        let a = Vec3::new(2, -3, 6);
+        let b = Vec3::new(1, SignedFractional::from_num(0.25), -2);
+        let (x, y) = (Vector::from(a), Vector::from(b));
+        let two = SignedFractional::from_num(2);
+
+        assert_eq!(x.len(), 7);
+        assert_eq!(x.len(), a.len());
+        assert_eq!(x.dot(y), a.dot(b));
+        assert_eq!(Vec3::from(x * two), a * two);
+        assert_eq!(Vec3::from(x / two), a / two);
+        assert_eq!(x.to_string(), a.to_string());
+        assert_eq!(format!("{x:.2}"), format!("{a:.2}"));
+    }
+
+    #[test]
+    fn four_components() {
+        let mut x = Vec4::new([1.into(), 3.into(), 3.into(), 9.into()]);
+        let y = Vec4::ONE;
+
+        assert_eq!(x.len(), 10);
+        assert_eq!(x.dot(y), 16);
+        assert_eq!(x + y, Vec4::new([2.into(), 4.into(), 4.into(), 10.into()]));
+
+        x -= y;
+        x *= SignedFractional::from_num(0.5);
+        assert_eq!(x, Vec4::new([0.into(), 1.into(), 1.into(), 4.into()]));
+
+        x[0] = 4.into();
+        assert_eq!(<[SignedFractional; 4]>::from(x)[0], 4);
+        assert_eq!(Vec4::default(), Vec4::ZERO);
+    }
+
+    #[test]
+    fn normalization() {
+        let epsilon = SignedFractional::from_num(0.000_1);
+        let x = Vec4::new([2.into(), 4.into(), 5.into(), 6.into()]);
+
+        assert!((x.get_normalized().len() - SignedFractional::ONE).abs() < epsilon);
+        assert_eq!(Vec4::ZERO.try_normalize(), Err(NormalizeError::ZeroVector));
+        assert_eq!(Vec4::ZERO.normalize_or_zero(), Vec4::ZERO);
+
+        let big = Vec4::new([40_000.into(), 40_000.into(), 40_000.into(), 40_000.into()]);
+        assert_eq!(big.len(), 80_000);
+    }
+
+    #[test]
+    fn generic_backing_type() {
+        // Out of range for I32F32 but not for I48F16
+        let far = Vector {
+            components: [
+                I48F16::from_num(3_000_000_000_i64),
+                I48F16::from_num(4_000_000_000_i64),
+            ],
+        };
+        let unit = Vector {
+            components: [I48F16::from_num(0.5); 4],
+        };
+
+        assert_eq!(far.len(), I48F16::from_num(5_000_000_000_i64));
+        assert_eq!(Vec2::from(far).len(), far.len());
+        assert_eq!(Vector::from(Vec2::from(far)), far);
+        assert_eq!(unit.len(), 1);
+        assert_eq!(unit.dot(unit * I48F16::from_num(2)), 2);
+    }
+}